@@ -0,0 +1,25 @@
+use core::ptr;
+
+use super::{Len, Ptr};
+
+/// **Trait `Clear<T>`**
+///
+/// Removes all elements at once, without touching the allocation.
+///
+/// - `__clear` - Drops all elements and sets the length to `0`.
+pub trait Clear<T>: Len + Ptr<T> {
+    /// Drops all elements in a single pass and sets the length to `0`.
+    ///
+    /// The capacity stays the same, as this never calls into the `Shrink` implementation.
+    ///
+    /// The elements are dropped front to back, like `Vec::clear` does.
+    fn __clear(&mut self) {
+        let len = self.__len();
+
+        // The length gets set first, so a panicking `drop` can not cause a double drop
+        self.__len_set(0);
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.__ptr().as_ptr(), len));
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use core::{ptr, slice};
+
+use super::{Len, Ptr, Shrink};
+
+/// Closes the gap left by the removed duplicates and fixes the length.
+///
+/// This also runs if a user supplied closure or a `drop` panics, so no element gets leaked
+/// or dropped twice.
+struct FillGapOnDrop<'a, S: Len + ?Sized, T> {
+    sector: &'a mut S,
+    base: *mut T,
+    /// Index of the next element to check
+    read: usize,
+    /// Index of the next free slot for an unique element
+    write: usize,
+    len: usize,
+}
+
+impl<S: Len + ?Sized, T> Drop for FillGapOnDrop<'_, S, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::copy(
+                self.base.add(self.read),
+                self.base.add(self.write),
+                self.len - self.read,
+            );
+        }
+        self.sector.__len_set(self.write + self.len - self.read);
+    }
+}
+
+/// **Trait `Dedup<T>`**
+///
+/// Removes consecutive duplicates in place, shrinking if necessary.
+///
+/// - `__dedup_by_key` - Removes consecutive elements that resolve to the same key.
+/// - `__dedup_by` - Removes consecutive elements that `same_bucket` considers equal.
+/// - `__dedup_copy` - Removes consecutive equal elements, specialized for `Copy` types.
+pub trait Dedup<T>: Len + Ptr<T> + Shrink<T> {
+    /// Removes all but the first of consecutive elements that resolve to the same key.
+    ///
+    /// The key of the last kept element is cached, so `key` gets called exactly once per
+    /// element, regardless of how long the runs of duplicates are.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Computes the key an element is compared by.
+    fn __dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        let len = self.__len();
+        if len <= 1 {
+            return;
+        }
+
+        let base = self.__ptr().as_ptr();
+        let mut gap = FillGapOnDrop {
+            sector: self,
+            base,
+            read: 1,
+            write: 1,
+            len,
+        };
+
+        let mut last_key = key(unsafe { &mut *base });
+        while gap.read < len {
+            unsafe {
+                let current = base.add(gap.read);
+                let current_key = key(&mut *current);
+                if current_key == last_key {
+                    // Counts as removed before dropping, in case the drop panics
+                    gap.read += 1;
+                    ptr::drop_in_place(current);
+                } else {
+                    ptr::copy(current, base.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                    last_key = current_key;
+                }
+            }
+        }
+
+        let new_len = gap.write;
+        drop(gap);
+
+        unsafe { self.__shrink(len, new_len) };
+    }
+
+    /// Removes all but the first of consecutive elements for which `same_bucket` returns `true`.
+    ///
+    /// `same_bucket` gets passed the current element first and the last kept element second.
+    ///
+    /// # Arguments
+    ///
+    /// * `same_bucket` - Decides whether two elements are duplicates.
+    fn __dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let len = self.__len();
+        if len <= 1 {
+            return;
+        }
+
+        let base = self.__ptr().as_ptr();
+        let mut gap = FillGapOnDrop {
+            sector: self,
+            base,
+            read: 1,
+            write: 1,
+            len,
+        };
+
+        while gap.read < len {
+            unsafe {
+                let current = base.add(gap.read);
+                let last_kept = base.add(gap.write - 1);
+                if same_bucket(&mut *current, &mut *last_kept) {
+                    // Counts as removed before dropping, in case the drop panics
+                    gap.read += 1;
+                    ptr::drop_in_place(current);
+                } else {
+                    ptr::copy(current, base.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
+        }
+
+        let new_len = gap.write;
+        drop(gap);
+
+        unsafe { self.__shrink(len, new_len) };
+    }
+
+    /// Removes consecutive equal elements.
+    ///
+    /// Gives the same result as [`__dedup_by`](Self::__dedup_by) with `==`, but plain copies
+    /// suffice for `Copy` types, so no drop guard is needed.
+    fn __dedup_copy(&mut self)
+    where
+        T: Copy + PartialEq,
+    {
+        let len = self.__len();
+        if len <= 1 {
+            return;
+        }
+
+        let elems = unsafe { slice::from_raw_parts_mut(self.__ptr().as_ptr(), len) };
+        let mut write = 1;
+        for read in 1..len {
+            if elems[read] != elems[write - 1] {
+                elems[write] = elems[read];
+                write += 1;
+            }
+        }
+
+        self.__len_set(write);
+        unsafe { self.__shrink(len, write) };
+    }
+}
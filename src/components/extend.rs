@@ -0,0 +1,129 @@
+use core::{ops::RangeBounds, ptr};
+
+use super::{Len, Ptr, Reserve};
+
+/// Moves the shifted elements back next to the prepended clones and fixes the length.
+///
+/// This also runs if a `clone` panics, so the shifted elements are neither leaked nor dropped
+/// twice.
+struct CloseGapOnDrop<'a, S: Len + ?Sized, T> {
+    sector: &'a mut S,
+    base: *mut T,
+    /// Number of clones written to the front so far
+    written: usize,
+    /// Index the old elements were shifted to
+    shifted_to: usize,
+    old_len: usize,
+}
+
+impl<S: Len + ?Sized, T> Drop for CloseGapOnDrop<'_, S, T> {
+    fn drop(&mut self) {
+        if self.written != self.shifted_to {
+            unsafe {
+                ptr::copy(
+                    self.base.add(self.shifted_to),
+                    self.base.add(self.written),
+                    self.old_len,
+                );
+            }
+        }
+        self.sector.__len_set(self.written + self.old_len);
+    }
+}
+
+/// **Trait `ExtendFrom<T>`**
+///
+/// Appends elements that are copied from somewhere else, growing if necessary.
+///
+/// - `__extend_from_within` - Appends clones of the elements in a range of the collection itself.
+/// - `__extend_from_slice` - Appends copies of the elements of a slice.
+/// - `__prepend_from_slice` - Inserts clones of the elements of a slice at the front.
+pub trait ExtendFrom<T>: Len + Ptr<T> + Reserve<T> {
+    /// Clones the elements in `src` and appends them to the end of the collection.
+    ///
+    /// The required capacity is reserved before anything is read, so growing the buffer can not
+    /// invalidate the elements that are still to be cloned.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - The range of elements to clone.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `src` is out of bounds or its start is greater than its end.
+    fn __extend_from_within<R: RangeBounds<usize>>(&mut self, src: R)
+    where
+        T: Clone,
+    {
+        let range = crate::sector::slice_range(src, self.__len());
+        self.__reserve(range.len());
+
+        // Only read after reserving, as that might have moved the buffer
+        let base = self.__ptr().as_ptr();
+        for i in range {
+            let len = self.__len();
+            unsafe {
+                let elem = (*base.add(i)).clone();
+                ptr::write(base.add(len), elem);
+            }
+            // Updated after every element, so a panicking `clone` can not leak or double drop
+            self.__len_set(len + 1);
+        }
+    }
+
+    /// Copies all elements of `other` to the end of the collection.
+    ///
+    /// The collection grows at most once, then all elements get copied at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The elements to append.
+    fn __extend_from_slice(&mut self, other: &[T])
+    where
+        T: Copy,
+    {
+        self.__reserve(other.len());
+
+        let len = self.__len();
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), self.__ptr().as_ptr().add(len), other.len());
+        }
+        self.__len_set(len + other.len());
+    }
+
+    /// Clones all elements of `items` to the front of the collection, keeping their order.
+    ///
+    /// Reserves once, shifts the existing elements once and then clones `items` in a single pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The elements to prepend.
+    fn __prepend_from_slice(&mut self, items: &[T])
+    where
+        T: Clone,
+    {
+        let count = items.len();
+        if count == 0 {
+            return;
+        }
+        self.__reserve(count);
+
+        let old_len = self.__len();
+        let base = self.__ptr().as_ptr();
+        unsafe { ptr::copy(base, base.add(count), old_len) };
+        // The front is uninitialized now, the guard fixes the length again
+        self.__len_set(0);
+
+        let mut gap = CloseGapOnDrop {
+            sector: self,
+            base,
+            written: 0,
+            shifted_to: count,
+            old_len,
+        };
+        for item in items {
+            unsafe { ptr::write(base.add(gap.written), item.clone()) };
+            gap.written += 1;
+        }
+    }
+}
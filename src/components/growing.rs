@@ -100,7 +100,13 @@ pub unsafe trait Grow<T>: Cap + Ptr<T> {
             }
         });
 
+        #[cfg(feature = "metrics")]
+        let old_cap = self.__cap();
         self.__cap_set(new_cap);
+
+        // Only call the hook once the sector is consistent again, it might panic
+        #[cfg(feature = "metrics")]
+        crate::metrics::notify_realloc(old_cap, new_cap);
         Ok(())
     }
 
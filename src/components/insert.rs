@@ -20,7 +20,10 @@ pub trait Insert<T>: Cap + Len + Ptr<T> + Grow<T> {
     /// - Panics if `index` is out of bounds.
     fn __insert(&mut self, index: usize, elem: T) {
         let len = self.__len();
-        assert!(index <= len, "Index out of bounds");
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
         self.__len_set(len + 1);
         if len == self.__cap() {
             unsafe { self.__grow(len, len + 1) };
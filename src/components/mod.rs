@@ -1,5 +1,8 @@
 mod capacity;
+mod clear;
+mod dedup;
 mod drain;
+mod extend;
 mod growing;
 mod index;
 mod insert;
@@ -9,12 +12,19 @@ mod pointer;
 mod pop;
 mod push;
 mod remove;
+mod reserve;
 mod resizing;
+mod retain;
+mod shrink_to;
 mod shrinking;
 pub(crate) mod testing;
+mod truncate;
 
 pub use capacity::Cap;
+pub use clear::Clear;
+pub use dedup::Dedup;
 pub use drain::DefaultDrain;
+pub use extend::ExtendFrom;
 pub use growing::Grow;
 pub use index::Index;
 pub use insert::Insert;
@@ -24,6 +34,10 @@ pub use pointer::Ptr;
 pub use pop::Pop;
 pub use push::Push;
 pub use remove::Remove;
+pub use reserve::Reserve;
 #[allow(unused_imports)]
 pub use resizing::Resize;
+pub use retain::Retain;
+pub use shrink_to::ShrinkTo;
 pub use shrinking::Shrink;
+pub use truncate::Truncate;
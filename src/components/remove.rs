@@ -7,6 +7,7 @@ use super::{Cap, Len, Ptr, Shrink};
 /// Removes an element from a specified index, shifting elements to fill the gap.
 ///
 /// - `__remove` - Removes and returns the element at the index.
+/// - `__swap_remove` - Removes and returns the element at the index, replacing it with the last one.
 pub trait Remove<T>: Cap + Len + Ptr<T> + Shrink<T> {
     /// Removes and returns the element at a specified index, shifting subsequent elements.
     ///
@@ -23,7 +24,10 @@ pub trait Remove<T>: Cap + Len + Ptr<T> + Shrink<T> {
     /// - Panics if `index` is out of bounds.
     fn __remove(&mut self, index: usize) -> T {
         let len = self.__len();
-        assert!(index < len, "Index out of bounds");
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
         let result = unsafe { ptr::read(self.__ptr().as_ptr().add(index)) };
         unsafe {
             ptr::copy(
@@ -39,4 +43,38 @@ pub trait Remove<T>: Cap + Len + Ptr<T> + Shrink<T> {
         }
         result
     }
+
+    /// Removes and returns the element at a specified index, replacing it with the last element.
+    ///
+    /// This does not preserve the ordering of the remaining elements, but is _O(1)_.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `T` - The removed element.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `index` is out of bounds.
+    fn __swap_remove(&mut self, index: usize) -> T {
+        let len = self.__len();
+        assert!(
+            index < len,
+            "swap_remove index (is {index}) should be < len (is {len})"
+        );
+        unsafe {
+            let base = self.__ptr().as_ptr();
+            let result = ptr::read(base.add(index));
+            ptr::copy(base.add(len - 1), base.add(index), 1);
+
+            self.__len_set(len - 1);
+
+            // Shrink implementation should handle reducing memory when necessary
+            self.__shrink(len, len - 1);
+            result
+        }
+    }
 }
@@ -0,0 +1,59 @@
+use core::mem;
+
+use super::{Cap, Grow, Len};
+
+/// **Trait `Reserve<T>`**
+///
+/// Makes room for additional elements up front, growing if necessary.
+///
+/// - `__reserve` - Ensures the capacity fits at least `additional` more elements.
+/// - `__reserve_exact` - Grows the capacity to exactly `len + additional` if it is not enough.
+pub trait Reserve<T>: Len + Cap + Grow<T> {
+    /// Ensures the capacity fits at least `additional` more elements.
+    ///
+    /// How much the capacity grows beyond that is up to the `Grow` implementation.
+    ///
+    /// # Arguments
+    ///
+    /// * `additional` - The number of elements that will be added.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the required capacity overflows `usize`.
+    /// - Panics if the `Grow` implementation does not correctly handle growth.
+    fn __reserve(&mut self, additional: usize) {
+        let required = self
+            .__len()
+            .checked_add(additional)
+            .expect("Capacity overflow");
+
+        if required > self.__cap() && mem::size_of::<T>() != 0 {
+            let cap = self.__cap();
+            unsafe { self.__grow(cap, required) };
+        }
+
+        assert!(required <= self.__cap(), "Incorrect Grow implementation");
+    }
+
+    /// Ensures the capacity fits at least `additional` more elements, growing it to exactly
+    /// `len + additional` if it does not.
+    ///
+    /// # Arguments
+    ///
+    /// * `additional` - The number of elements that will be added.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the required capacity overflows `usize` or the maximum allocation size.
+    fn __reserve_exact(&mut self, additional: usize) {
+        let required = self
+            .__len()
+            .checked_add(additional)
+            .expect("Capacity overflow");
+
+        if required > self.__cap() && mem::size_of::<T>() != 0 {
+            let missing = required - self.__cap();
+            self.__grow_manually_unchecked(missing);
+        }
+    }
+}
@@ -47,7 +47,13 @@ pub trait Resize<T>: Cap + Ptr<T> {
             None => alloc::handle_alloc_error(new_layout),
         };
 
+        #[cfg(feature = "metrics")]
+        let old_cap = self.__cap();
         self.__cap_set(new_cap);
         self.__ptr_set(new_ptr);
+
+        // Only call the hook once the sector is consistent again, it might panic
+        #[cfg(feature = "metrics")]
+        crate::metrics::notify_realloc(old_cap, new_cap);
     }
 }
@@ -0,0 +1,117 @@
+use core::{
+    ops::{Range, RangeBounds},
+    ptr,
+};
+
+use super::{Len, Ptr, Shrink};
+
+/// Shifts the kept elements over the removed ones and fixes the length.
+///
+/// This also runs if `f` or a `drop` panics, so no element gets leaked or dropped twice.
+struct BackshiftOnDrop<'a, S: Len + ?Sized, T> {
+    sector: &'a mut S,
+    base: *mut T,
+    /// Number of elements that were already checked
+    processed: usize,
+    /// Number of elements that were removed so far
+    deleted: usize,
+    original_len: usize,
+}
+
+impl<S: Len + ?Sized, T> Drop for BackshiftOnDrop<'_, S, T> {
+    fn drop(&mut self) {
+        if self.deleted > 0 {
+            unsafe {
+                ptr::copy(
+                    self.base.add(self.processed),
+                    self.base.add(self.processed - self.deleted),
+                    self.original_len - self.processed,
+                );
+            }
+        }
+        self.sector.__len_set(self.original_len - self.deleted);
+    }
+}
+
+/// **Trait `Retain<T>`**
+///
+/// Removes all elements that do not match a predicate, shrinking if necessary.
+///
+/// - `__retain_mut` - Keeps only the elements for which the predicate returns `true`.
+/// - `__retain_range_mut` - Like `__retain_mut`, but only checks the elements within a range.
+pub trait Retain<T>: Len + Ptr<T> + Shrink<T> {
+    /// Keeps only the elements for which `f` returns `true`, preserving their order.
+    ///
+    /// Every element is visited exactly once, front to back. Rejected elements are dropped right
+    /// away, so they are dropped in that order too.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Decides whether an element is kept.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of removed elements.
+    fn __retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> usize {
+        self.__retain_range_mut(.., f)
+    }
+
+    /// Keeps only the elements within `range` for which `f` returns `true`, preserving their
+    /// order.
+    ///
+    /// Elements outside of `range` are never passed to `f` and always kept; the ones after it get
+    /// shifted over the gap.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The elements to check.
+    /// * `f` - Decides whether an element is kept.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of removed elements.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds or its start is greater than its end.
+    fn __retain_range_mut<R: RangeBounds<usize>, F: FnMut(&mut T) -> bool>(
+        &mut self,
+        range: R,
+        mut f: F,
+    ) -> usize {
+        let len = self.__len();
+        let Range { start, end } = crate::sector::slice_range(range, len);
+        let base = self.__ptr().as_ptr();
+
+        let mut guard = BackshiftOnDrop {
+            sector: self,
+            base,
+            processed: start,
+            deleted: 0,
+            original_len: len,
+        };
+
+        while guard.processed < end {
+            unsafe {
+                let current = base.add(guard.processed);
+                if f(&mut *current) {
+                    if guard.deleted > 0 {
+                        ptr::copy_nonoverlapping(current, current.sub(guard.deleted), 1);
+                    }
+                    guard.processed += 1;
+                } else {
+                    // Counts as removed before dropping, in case the drop panics
+                    guard.processed += 1;
+                    guard.deleted += 1;
+                    ptr::drop_in_place(current);
+                }
+            }
+        }
+
+        let deleted = guard.deleted;
+        drop(guard);
+
+        unsafe { self.__shrink(len, len - deleted) };
+        deleted
+    }
+}
@@ -0,0 +1,33 @@
+use core::{cmp, mem};
+
+use super::{Cap, Len, Shrink};
+
+/// **Trait `ShrinkTo<T>`**
+///
+/// Releases unused capacity on request, independent of the automatic `__shrink` behavior.
+///
+/// - `__shrink_to` - Shrinks the capacity as close to a lower bound as the length allows.
+/// - `__shrink_to_fit` - Shrinks the capacity to exactly the length.
+pub trait ShrinkTo<T>: Len + Cap + Shrink<T> {
+    /// Shrinks the capacity to `max(len, min_capacity)`.
+    ///
+    /// Does nothing if the capacity is already at or below that, or if `T` is zero-sized.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_capacity` - The capacity to keep at least.
+    fn __shrink_to(&mut self, min_capacity: usize) {
+        let target = cmp::max(self.__len(), min_capacity);
+        if mem::size_of::<T>() == 0 || target >= self.__cap() {
+            return;
+        }
+
+        let excess = self.__cap() - target;
+        self.__shrink_manually_unchecked(excess);
+    }
+
+    /// Shrinks the capacity to exactly the length, freeing the allocation if it is empty.
+    fn __shrink_to_fit(&mut self) {
+        self.__shrink_to(0);
+    }
+}
@@ -89,8 +89,14 @@ pub unsafe trait Shrink<T>: Cap + Ptr<T> {
             NonNull::dangling()
         };
 
+        #[cfg(feature = "metrics")]
+        let old_cap = self.__cap();
         self.__ptr_set(new_ptr);
         self.__cap_set(new_cap);
+
+        // Only call the hook once the sector is consistent again, it might panic
+        #[cfg(feature = "metrics")]
+        crate::metrics::notify_realloc(old_cap, new_cap);
         Ok(())
     }
 
@@ -1,3 +1,10 @@
+#[cfg(not(feature = "std"))]
+extern crate alloc as no_std_alloc;
+/// Lets tests use `String` without the `std` feature
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+pub(crate) use no_std_alloc::string::String;
+
 #[derive(Debug)]
 #[allow(dead_code)]
 pub(crate) struct ZeroSizedType;
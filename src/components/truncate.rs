@@ -0,0 +1,81 @@
+use core::ptr;
+
+use super::{Len, Ptr, Shrink};
+
+/// **Trait `Truncate<T>`**
+///
+/// Shortens the collection by dropping elements from either end, shrinking if necessary.
+///
+/// - `__truncate` - Keeps the first `new_len` elements and drops the rest.
+/// - `__truncate_front` - Keeps the last `n` elements and drops the rest.
+pub trait Truncate<T>: Len + Ptr<T> + Shrink<T> {
+    /// Shortens the collection, keeping the first `new_len` elements and dropping the rest.
+    ///
+    /// Does nothing if `new_len` is greater or equal to the current length. The removed elements
+    /// are dropped front to back.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_len` - The length of the collection afterwards.
+    fn __truncate(&mut self, new_len: usize) {
+        let len = self.__len();
+        if new_len >= len {
+            return;
+        }
+
+        // The length gets set first, so a panicking `drop` can not cause a double drop
+        self.__len_set(new_len);
+        unsafe {
+            let tail =
+                ptr::slice_from_raw_parts_mut(self.__ptr().as_ptr().add(new_len), len - new_len);
+            ptr::drop_in_place(tail);
+
+            self.__shrink(len, new_len);
+        }
+    }
+
+    /// Shortens the collection, keeping the last `n` elements and dropping the rest.
+    ///
+    /// The kept elements get shifted to the front. Does nothing if `n` is greater or equal to the
+    /// current length.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of elements to keep.
+    fn __truncate_front(&mut self, n: usize) {
+        let len = self.__len();
+        if n >= len {
+            return;
+        }
+
+        /// Moves the kept elements to the front, even if dropping the removed ones panics.
+        struct ShiftGuard<'a, S: Len + ?Sized, T> {
+            sector: &'a mut S,
+            base: *mut T,
+            removed: usize,
+            kept: usize,
+        }
+
+        impl<S: Len + ?Sized, T> Drop for ShiftGuard<'_, S, T> {
+            fn drop(&mut self) {
+                unsafe { ptr::copy(self.base.add(self.removed), self.base, self.kept) };
+                self.sector.__len_set(self.kept);
+            }
+        }
+
+        let removed = len - n;
+        let base = self.__ptr().as_ptr();
+        self.__len_set(0);
+        {
+            let _guard = ShiftGuard {
+                sector: self,
+                base,
+                removed,
+                kept: n,
+            };
+            unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(base, removed)) };
+        }
+
+        unsafe { self.__shrink(len, n) };
+    }
+}
@@ -58,6 +58,7 @@
 //! - [`components`](crate::components) – Internal traits defining vector operations.
 //! - [`sector`](crate::sector::Sector) – Core implementation of `Sector`.
 //! - [`states`](crate::states) – Definitions of different memory management states.
+//! - `metrics` – Hooks to observe reallocations (requires the `metrics` feature).
 //!
 //! ---
 //!
@@ -68,7 +69,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod components;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 mod sector;
 pub mod states;
 
-pub use sector::Sector;
+pub use sector::{CollectInto, Entry, Sector, VacantEntry};
@@ -0,0 +1,49 @@
+//! # Reallocation Metrics
+//!
+//! With the `metrics` feature enabled, a hook can be registered that gets called whenever a sector
+//! grows or shrinks its allocation. This makes it possible to profile the allocation behavior of
+//! the different states in a running application.
+//!
+//! The hook is stored per thread, so it only observes sectors that reallocate on the thread that
+//! registered it.
+//!
+//! ```rust
+//! use sector::{metrics, states::Normal, Sector};
+//!
+//! metrics::set_realloc_hook(|old_cap, new_cap| println!("{old_cap} -> {new_cap}"));
+//!
+//! let mut sector: Sector<Normal, i32> = Sector::new();
+//! sector.push(1);
+//!
+//! metrics::clear_realloc_hook();
+//! ```
+
+use std::{boxed::Box, cell::RefCell};
+
+type ReallocHook = Box<dyn Fn(usize, usize)>;
+
+std::thread_local! {
+    static REALLOC_HOOK: RefCell<Option<ReallocHook>> = const { RefCell::new(None) };
+}
+
+/// Registers `hook` to be called with the old and the new capacity after every reallocation on
+/// the current thread.
+///
+/// Replaces any previously registered hook.
+pub fn set_realloc_hook(hook: impl Fn(usize, usize) + 'static) {
+    REALLOC_HOOK.with(|cell| *cell.borrow_mut() = Some(Box::new(hook)));
+}
+
+/// Removes the hook of the current thread, if one is registered.
+pub fn clear_realloc_hook() {
+    REALLOC_HOOK.with(|cell| *cell.borrow_mut() = None);
+}
+
+/// Reports a reallocation from `old_cap` to `new_cap` to the registered hook.
+pub(crate) fn notify_realloc(old_cap: usize, new_cap: usize) {
+    REALLOC_HOOK.with(|cell| {
+        if let Some(hook) = cell.borrow().as_ref() {
+            hook(old_cap, new_cap);
+        }
+    });
+}
@@ -1,43 +1,57 @@
 use core::{
     alloc::Layout,
+    cmp, fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
     marker::PhantomData,
     mem,
-    ops::{Deref, DerefMut},
+    ops::{Bound, Deref, DerefMut, Range, RangeBounds},
     ptr::{self, NonNull},
-    slice,
+    slice::{self, Iter, Windows},
 };
 
 #[cfg(feature = "std")]
-use std::alloc;
+use std::{alloc, borrow::Cow, string::String, vec::Vec};
 
 #[cfg(not(feature = "std"))]
 extern crate alloc as no_std_alloc;
 
 #[cfg(not(feature = "std"))]
-use no_std_alloc::alloc;
+use no_std_alloc::{alloc, string::String};
 
 use try_reserve::error::TryReserveError;
 
+use crate::{
+    components::{ExtendFrom, Push, Reserve},
+    states::StateName,
+};
+
 pub struct Sector<State, T> {
     pub(super) buf: RawSec<T>,
     pub(super) len: usize,
+    /// The capacity requested on creation, which shrinking states do not go below
+    pub(super) min_cap: usize,
     pub(super) _state: PhantomData<State>,
 }
 
 impl<State, T> Sector<State, T> {
-    #[allow(clippy::new_without_default)]
     pub fn new() -> Sector<State, T> {
         Sector {
             buf: RawSec::new(),
             len: 0,
+            min_cap: 0,
             _state: PhantomData,
         }
     }
 
+    /// Creates an empty sector with room for `capacity` elements.
+    ///
+    /// A `capacity` of `0` does not allocate and behaves exactly like [`Sector::new`].
     pub fn with_capacity(capacity: usize) -> Sector<State, T> {
         Sector {
             buf: RawSec::with_capacity(capacity),
             len: 0,
+            min_cap: capacity,
             _state: PhantomData,
         }
     }
@@ -46,10 +60,31 @@ impl<State, T> Sector<State, T> {
         Ok(Sector {
             buf: RawSec::try_with_capacity(capacity)?,
             len: 0,
+            min_cap: capacity,
             _state: PhantomData,
         })
     }
 
+    /// Creates a sector that takes ownership of an existing allocation.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by the global allocator with the layout of `[T; capacity]`
+    /// (or be dangling if `capacity` is `0` or `T` is zero-sized), and its first `len` elements
+    /// must be initialized.
+    pub(crate) unsafe fn from_raw_parts(ptr: NonNull<T>, len: usize, capacity: usize) -> Self {
+        Sector {
+            buf: RawSec {
+                ptr,
+                cap: if size_of::<T>() == 0 { !0 } else { capacity },
+                zst_cap: capacity,
+            },
+            len,
+            min_cap: 0,
+            _state: PhantomData,
+        }
+    }
+
     //  TODO: DOC on how unsafe using this is. Can point to NULL
     #[allow(dead_code)]
     pub unsafe fn as_ptr(&self) -> NonNull<T> {
@@ -69,7 +104,14 @@ impl<State, T> Sector<State, T> {
         self.buf.ptr = new_ptr;
     }
 
-    #[allow(dead_code)]
+    /// Returns the number of elements the sector can hold without reallocating.
+    ///
+    /// For zero-sized types this is always `usize::MAX`, since they never need an allocation.
+    /// Growable states therefore never attempt to grow when pushing them.
+    ///
+    /// `Fixed` and `Manual` sectors of zero-sized types still enforce a logical capacity that this
+    /// value does not reflect: the one passed to `with_capacity`, adjusted by `Manual::grow`,
+    /// `Manual::shrink` and `Manual::reserve_exact`. A sector created with `new` has no limit.
     pub fn capacity(&self) -> usize {
         self.buf.cap
     }
@@ -80,11 +122,16 @@ impl<State, T> Sector<State, T> {
         self.buf.cap = new_cap;
     }
 
-    #[allow(dead_code)]
+    /// Returns the number of elements in the sector.
     pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Returns `true` if the sector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     //  TODO: DOC on how unsafe using this is. it is. REALLY UNSAFE!
     #[allow(dead_code)]
     pub unsafe fn set_len(&mut self, new_len: usize) {
@@ -94,17 +141,72 @@ impl<State, T> Sector<State, T> {
 
 impl<State, T> Drop for Sector<State, T> {
     fn drop(&mut self) {
-        if self.len > 0 && mem::size_of::<T>() != 0 {
-            for i in 0..self.len {
-                unsafe {
-                    let ptr = self.buf.ptr.as_ptr().add(i);
-                    ptr::drop_in_place(ptr);
-                }
-            }
+        // Types without drop glue (e.g. `Copy` types) do not need to be visited at all
+        if mem::needs_drop::<T>() {
+            unsafe { ptr::drop_in_place(&mut **self as *mut [T]) };
         }
     }
 }
 
+impl<State, T> Default for Sector<State, T> {
+    /// Creates an empty sector, like [`Sector::new`].
+    fn default() -> Self {
+        Sector::new()
+    }
+}
+
+impl<State, T: Clone> Clone for Sector<State, T> {
+    /// Clones the sector into a fresh allocation, sized to the current length.
+    fn clone(&self) -> Self {
+        let mut clone: Self = Sector::with_capacity(self.len);
+        clone.min_cap = self.min_cap.min(self.len);
+
+        for (i, elem) in self.iter().enumerate() {
+            // The length grows with every element, so a panicking `clone` drops the finished ones
+            unsafe { ptr::write(clone.buf.ptr.as_ptr().add(i), elem.clone()) };
+            clone.len = i + 1;
+        }
+        clone
+    }
+}
+
+impl<State: StateName, T: fmt::Debug> fmt::Debug for Sector<State, T> {
+    /// Formats the sector like `Sector<Normal> [1, 2, 3]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Sector<{}> ", State::NAME)?;
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<S1, S2, T: PartialEq<U>, U> PartialEq<Sector<S2, U>> for Sector<S1, T> {
+    /// Compares the elements only, regardless of the state and capacity.
+    fn eq(&self, other: &Sector<S2, U>) -> bool {
+        **self == **other
+    }
+}
+
+impl<State, T: Eq> Eq for Sector<State, T> {}
+
+impl<State, T: PartialEq<U>, U> PartialEq<[U]> for Sector<State, T> {
+    fn eq(&self, other: &[U]) -> bool {
+        **self == *other
+    }
+}
+
+impl<State, T: PartialEq<U>, U> PartialEq<&[U]> for Sector<State, T> {
+    fn eq(&self, other: &&[U]) -> bool {
+        **self == **other
+    }
+}
+
+impl<State, T: Hash> Hash for Sector<State, T> {
+    /// Hashes the elements like a slice does, so equal sectors hash equally regardless of the state
+    /// and capacity.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state);
+    }
+}
+
 impl<State, T> Deref for Sector<State, T> {
     type Target = [T];
 
@@ -119,9 +221,618 @@ impl<State, T> DerefMut for Sector<State, T> {
     }
 }
 
+impl<State, T> Sector<State, T> {
+    /// Moves all elements of `other` to the end of `self`, leaving `other` empty.
+    ///
+    /// # Safety
+    ///
+    /// `self` must have room for at least `other.len()` more elements.
+    pub(crate) unsafe fn move_from_unchecked<Other>(&mut self, other: &mut Sector<Other, T>) {
+        let count = other.len;
+        debug_assert!(self.buf.cap - self.len >= count);
+
+        ptr::copy_nonoverlapping(
+            other.buf.ptr.as_ptr(),
+            self.buf.ptr.as_ptr().add(self.len),
+            count,
+        );
+        other.len = 0;
+        self.len += count;
+    }
+
+    /// Moves the elements `at..len` into a newly allocated sector, leaving `0..at` in `self`.
+    ///
+    /// The capacity of `self` stays untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub(crate) fn split_off_into<Target>(&mut self, at: usize) -> Sector<Target, T> {
+        let len = self.len;
+        assert!(
+            at <= len,
+            "`at` split index (is {at}) should be <= len (is {len})"
+        );
+
+        let count = len - at;
+        let mut other: Sector<Target, T> = Sector::with_capacity(count);
+        unsafe {
+            ptr::copy_nonoverlapping(self.buf.ptr.as_ptr().add(at), other.buf.ptr.as_ptr(), count);
+        }
+        self.len = at;
+        other.len = count;
+        other
+    }
+
+    /// Exchanges the contents of two sectors of the same state in O(1), without reallocating.
+    ///
+    /// The buffers, lengths and capacities are swapped as a whole. This is not called `swap`, so it
+    /// does not shadow [`slice::swap`] which swaps two elements.
+    pub fn swap_sectors(&mut self, other: &mut Self) {
+        mem::swap(self, other);
+    }
+
+    /// Moves the contents out of the sector, leaving an empty one of the same state behind.
+    ///
+    /// Like [`mem::take`], this only moves the buffer and length and never allocates.
+    pub fn take(&mut self) -> Self {
+        mem::take(self)
+    }
+
+    /// Returns the number of elements for which `pred` returns `true`.
+    pub fn count<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().filter(|elem| pred(elem)).count()
+    }
+
+    /// Returns `true` if `f` returns `true` for every element.
+    ///
+    /// An empty sector always returns `true`.
+    pub fn all<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+        self.iter().all(f)
+    }
+
+    /// Returns `true` if `f` returns `true` for at least one element.
+    ///
+    /// An empty sector always returns `false`.
+    pub fn any<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+        self.iter().any(f)
+    }
+
+    /// Folds all elements into a single value without consuming the sector.
+    ///
+    /// Starts with `init` and passes the accumulator together with every element to `f`, in
+    /// order, returning the final accumulator.
+    pub fn fold_ref<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Sums every `factor` consecutive elements into one, e.g. to reduce a time series.
+    ///
+    /// A trailing group with fewer than `factor` elements gets summed as well.
+    ///
+    /// # Panics
+    ///
+    /// If `factor` is `0`.
+    pub fn downsample_sum(&self, factor: usize) -> Sector<crate::states::Normal, T>
+    where
+        T: Copy + core::iter::Sum,
+    {
+        assert!(factor != 0, "downsample factor must be non-zero");
+
+        let mut sums: Sector<crate::states::Normal, T> =
+            Sector::with_capacity(self.len.div_ceil(factor));
+        for group in self.chunks(factor) {
+            sums.push(group.iter().copied().sum());
+        }
+        sums
+    }
+
+    /// Calls `f` on every element in order, stopping at the first error and returning it.
+    pub fn try_for_each<E, F: FnMut(&T) -> Result<(), E>>(&self, f: F) -> Result<(), E> {
+        self.iter().try_for_each(f)
+    }
+
+    /// Returns an iterator over all elements as mutable references, paired with their index.
+    ///
+    /// The sector can not change its length while the iterator is alive, so the indices always
+    /// refer to the element they are yielded with.
+    pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        self.iter_mut().enumerate()
+    }
+
+    /// Turns the sector into an iterator over owned windows of `size` elements, where each window
+    /// starts `step` elements after the previous one.
+    ///
+    /// The windows overlap if `step < size`. Elements are cloned into each window, and trailing
+    /// elements that do not fill a whole window are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` or `step` is `0`.
+    pub fn into_sliding_chunks(
+        self,
+        size: usize,
+        step: usize,
+    ) -> impl Iterator<Item = Sector<crate::states::Normal, T>>
+    where
+        T: Clone,
+    {
+        assert!(size != 0, "window size must be non-zero");
+        assert!(step != 0, "step must be non-zero");
+
+        let mut start: usize = 0;
+        core::iter::from_fn(move || {
+            let window = self.get(start..start.checked_add(size)?)?;
+            start = start.saturating_add(step);
+            Some(window.iter().cloned().collect_into_sector())
+        })
+    }
+
+    /// Returns an iterator over every `step`-th element, starting with the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `0`.
+    pub fn stride(&self, step: usize) -> impl Iterator<Item = &T> {
+        self.iter().step_by(step)
+    }
+
+    /// Splits the elements into chunks of `N` elements, plus a remainder shorter than `N`.
+    ///
+    /// Fixed-width chunks can be handed directly to SIMD code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is `0`.
+    pub fn as_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        (**self).as_chunks_mut()
+    }
+
+    /// Applies `f` to every full chunk of `N` elements, skipping the remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is `0`.
+    pub fn map_lanes<const N: usize, F: FnMut(&mut [T; N])>(&mut self, f: F) {
+        self.as_chunks_mut::<N>().0.iter_mut().for_each(f);
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is `0`.
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        (**self).windows(size)
+    }
+
+    /// Returns an iterator over all pairs of adjacent elements.
+    ///
+    /// Yields nothing if the sector has less than two elements.
+    pub fn pairwise(&self) -> impl Iterator<Item = (&T, &T)> {
+        self.iter().zip(self.iter().skip(1))
+    }
+}
+
+impl<State, T> FromIterator<T> for Sector<State, T>
+where
+    Sector<State, T>: Reserve<T> + Push<T>,
+{
+    /// Collects the iterator, reserving room for the lower bound of its size hint up front.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter().collect_into_sector()
+    }
+}
+
+impl<State, T> Extend<T> for Sector<State, T>
+where
+    Sector<State, T>: Reserve<T> + Push<T>,
+{
+    /// Appends every element of `iter` to the end of the sector.
+    ///
+    /// Room for the lower bound of the iterator's size hint is reserved up front, any elements
+    /// beyond that grow the sector like pushing them would.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.__reserve(iter.size_hint().0);
+        for elem in iter {
+            self.__push(elem);
+        }
+    }
+}
+
+impl<'a, State, T: Copy + 'a> Extend<&'a T> for Sector<State, T>
+where
+    Sector<State, T>: Reserve<T> + Push<T>,
+{
+    /// Copies every element of `iter` to the end of the sector.
+    ///
+    /// Room for the lower bound of the iterator's size hint is reserved up front.
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.__reserve(iter.size_hint().0);
+        for &elem in iter {
+            self.__push(elem);
+        }
+    }
+}
+
+impl<State, T> Sector<State, T>
+where
+    Sector<State, T>: Reserve<T> + Push<T>,
+{
+    /// Gets the entry at `index`, which is occupied if `index` is in bounds and vacant otherwise.
+    ///
+    /// A vacant entry can grow the sector to make `index` valid, similar to `HashMap::entry`.
+    pub fn entry(&mut self, index: usize) -> Entry<'_, State, T> {
+        if index < self.len {
+            Entry::Occupied(&mut self[index])
+        } else {
+            Entry::Vacant(VacantEntry {
+                sector: self,
+                index,
+            })
+        }
+    }
+}
+
+impl<State: crate::components::DefaultIter, T> Sector<State, T> {
+    /// Consumes the sector and folds its elements into an accumulator, stopping at the first
+    /// error.
+    ///
+    /// Elements that were not visited because of an early return get dropped.
+    pub fn try_fold_into<B, E, F: FnMut(B, T) -> Result<B, E>>(
+        self,
+        init: B,
+        f: F,
+    ) -> Result<B, E> {
+        self.into_iter().try_fold(init, f)
+    }
+
+    /// Consumes the sector and collapses every run of consecutive equal elements into a
+    /// `(value, run_length)` pair.
+    ///
+    /// The first element of each run is kept as the value, the others are dropped.
+    pub fn run_length_encode(self) -> Sector<crate::states::Normal, (T, usize)>
+    where
+        T: PartialEq,
+    {
+        let mut runs: Sector<crate::states::Normal, (T, usize)> = Sector::new();
+        for elem in self {
+            match runs.last_mut() {
+                Some((value, count)) if *value == elem => *count += 1,
+                _ => runs.push((elem, 1)),
+            }
+        }
+        runs
+    }
+
+    /// Consumes the sector and maps every element with `f` into a new `Normal` sector.
+    ///
+    /// If `U` has the same size and alignment as `T`, the elements are mapped in place and the
+    /// allocation is reused. Otherwise the mapped elements are moved into a fresh allocation.
+    pub fn map_into<U, F: FnMut(T) -> U>(self, mut f: F) -> Sector<crate::states::Normal, U> {
+        if mem::size_of::<U>() != mem::size_of::<T>()
+            || mem::align_of::<U>() != mem::align_of::<T>()
+        {
+            let mut mapped: Sector<crate::states::Normal, U> = Sector::with_capacity(self.len);
+            mapped.extend(self.into_iter().map(f));
+            return mapped;
+        }
+
+        let len = self.len;
+        let buf = unsafe { ptr::read(&self.buf) };
+        mem::forget(self);
+
+        let mut guard = MapInPlaceGuard::<T, U> {
+            buf,
+            len,
+            read: 0,
+            written: 0,
+            _mapped: PhantomData,
+        };
+        let src = guard.buf.ptr.as_ptr();
+        let dst = src as *mut U;
+        while guard.read < len {
+            unsafe {
+                let elem = ptr::read(src.add(guard.read));
+                // Counts as moved out before calling `f`, in case it panics
+                guard.read += 1;
+                ptr::write(dst.add(guard.written), f(elem));
+                guard.written += 1;
+            }
+        }
+
+        let buf = unsafe { ptr::read(&guard.buf) };
+        mem::forget(guard);
+
+        let mapped = Sector {
+            buf: RawSec {
+                ptr: buf.ptr.cast(),
+                cap: buf.cap,
+                zst_cap: buf.zst_cap,
+            },
+            len,
+            min_cap: 0,
+            _state: PhantomData,
+        };
+        // The allocation is now owned by `mapped`
+        mem::forget(buf);
+        mapped
+    }
+}
+
+impl<State: crate::components::DefaultIter, A, B> Sector<State, (A, B)> {
+    /// Consumes a sector of pairs and splits it into a sector of the first and a sector of the
+    /// second elements.
+    ///
+    /// Both sectors are allocated with exactly the length of the source.
+    pub fn unzip_into(
+        self,
+    ) -> (
+        Sector<crate::states::Normal, A>,
+        Sector<crate::states::Normal, B>,
+    ) {
+        let mut left: Sector<crate::states::Normal, A> = Sector::with_capacity(self.len);
+        let mut right: Sector<crate::states::Normal, B> = Sector::with_capacity(self.len);
+        for (a, b) in self {
+            left.push(a);
+            right.push(b);
+        }
+        (left, right)
+    }
+}
+
+impl<State: crate::components::DefaultIter, Inner, T> Sector<State, Sector<Inner, T>> {
+    /// Concatenates all inner sectors into a single `Normal` sector.
+    ///
+    /// If the first inner sector has enough capacity to hold all elements, the others get moved
+    /// into it and its allocation is reused. Otherwise a single allocation of the exact total
+    /// length is made.
+    pub fn flatten_in_place(self) -> Sector<crate::states::Normal, T> {
+        let total: usize = self.iter().map(|inner| inner.len).sum();
+        let mut inners = self.into_iter();
+
+        let mut flat = match inners.next() {
+            Some(first) if first.buf.cap >= total => first.to_custom(),
+            Some(mut first) => {
+                let mut flat = Sector::with_capacity(total);
+                unsafe { flat.move_from_unchecked(&mut first) };
+                flat
+            }
+            None => return Sector::new(),
+        };
+
+        for mut inner in inners {
+            unsafe { flat.move_from_unchecked(&mut inner) };
+        }
+        flat
+    }
+}
+
+impl<State, T: PartialEq> Sector<State, T> {
+    /// Splits the sector at the first occurrence of `delim`.
+    ///
+    /// Returns the elements before and after the delimiter, or `None` if it is not present.
+    pub fn split_once(&self, delim: &T) -> Option<(&[T], &[T])> {
+        let index = self.iter().position(|elem| elem == delim)?;
+        Some((&self[..index], &self[index + 1..]))
+    }
+
+    /// Splits the sector at the last occurrence of `delim`.
+    ///
+    /// Returns the elements before and after the delimiter, or `None` if it is not present.
+    pub fn rsplit_once(&self, delim: &T) -> Option<(&[T], &[T])> {
+        let index = self.iter().rposition(|elem| elem == delim)?;
+        Some((&self[..index], &self[index + 1..]))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<State, T> From<Vec<T>> for Sector<State, T> {
+    /// Takes over the allocation of `vec`, keeping its length and capacity.
+    fn from(vec: Vec<T>) -> Self {
+        let mut vec = mem::ManuallyDrop::new(vec);
+        let (len, capacity) = (vec.len(), vec.capacity());
+        let ptr = unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) };
+
+        unsafe { Sector::from_raw_parts(ptr, len, capacity) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<State, T> Sector<State, T> {
+    /// Converts the sector into a `Vec`, handing over the allocation without copying.
+    ///
+    /// Length and capacity stay the same.
+    pub fn into_vec(self) -> Vec<T> {
+        let sector = mem::ManuallyDrop::new(self);
+        unsafe { Vec::from_raw_parts(sector.buf.ptr.as_ptr(), sector.len, sector.buf.cap) }
+    }
+
+    /// Borrows the elements as a `Cow`, for APIs that accept either borrowed or owned slices.
+    pub fn to_cow(&self) -> Cow<'_, [T]>
+    where
+        T: Clone,
+    {
+        Cow::Borrowed(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Clone> Sector<crate::states::Normal, T> {
+    /// Creates a sector from a `Cow`.
+    ///
+    /// An owned `Vec` hands over its allocation without copying, a borrowed slice gets cloned.
+    pub fn from_cow(cow: Cow<'_, [T]>) -> Self {
+        match cow {
+            Cow::Borrowed(slice) => slice.iter().cloned().collect(),
+            Cow::Owned(vec) => Sector::from(vec),
+        }
+    }
+}
+
+impl<State, T, const N: usize> From<[T; N]> for Sector<State, T> {
+    /// Moves the elements of `array` into a new sector with a capacity of exactly `N`.
+    fn from(array: [T; N]) -> Self {
+        let mut sector = Sector::with_capacity(N);
+        let array = mem::ManuallyDrop::new(array);
+        unsafe { ptr::copy_nonoverlapping(array.as_ptr(), sector.buf.ptr.as_ptr(), N) };
+        sector.len = N;
+        sector
+    }
+}
+
+impl From<&str> for Sector<crate::states::Normal, u8> {
+    /// Copies the UTF-8 bytes of `s` into a new sector.
+    fn from(s: &str) -> Self {
+        let mut sector = Sector::new();
+        sector.__extend_from_slice(s.as_bytes());
+        sector
+    }
+}
+
+impl From<String> for Sector<crate::states::Normal, u8> {
+    /// Takes over the allocation of `s` without copying its bytes.
+    fn from(s: String) -> Self {
+        let mut bytes = mem::ManuallyDrop::new(s.into_bytes());
+        let (len, capacity) = (bytes.len(), bytes.capacity());
+        let ptr = unsafe { NonNull::new_unchecked(bytes.as_mut_ptr()) };
+
+        unsafe { Sector::from_raw_parts(ptr, len, capacity) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<State> Sector<State, String> {
+    /// Concatenates all elements into a single `String`, placing `sep` between each of them.
+    ///
+    /// The result is allocated once with the exact total length.
+    pub fn join(&self, sep: &str) -> String {
+        let total =
+            self.iter().map(String::len).sum::<usize>() + sep.len() * self.len.saturating_sub(1);
+
+        let mut joined = String::with_capacity(total);
+        for (i, elem) in self.iter().enumerate() {
+            if i > 0 {
+                joined.push_str(sep);
+            }
+            joined.push_str(elem);
+        }
+        joined
+    }
+}
+
+impl<State> Sector<State, u8> {
+    /// Feeds the bytes into `state`, exactly like hashing the equivalent `&[u8]` or `Vec<u8>`.
+    ///
+    /// The bytes are written with a single `Hasher::write` call instead of one call per element.
+    pub fn hash_bytes<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state);
+    }
+}
+
+impl<State> Sector<State, f64> {
+    /// Checks whether the sector has the same length as `other` and every element is within
+    /// `epsilon` of its counterpart.
+    ///
+    /// Equal infinities are approximately equal. An infinity is never within a finite `epsilon`
+    /// of a finite value or of the opposite infinity. `NaN` is never approximately equal to
+    /// anything, including itself.
+    pub fn approx_eq(&self, other: &[f64], epsilon: f64) -> bool {
+        self.len == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a == b || (a - b).abs() <= epsilon)
+    }
+}
+
+impl<State> Sector<State, f32> {
+    /// Checks whether the sector has the same length as `other` and every element is within
+    /// `epsilon` of its counterpart.
+    ///
+    /// Equal infinities are approximately equal. An infinity is never within a finite `epsilon`
+    /// of a finite value or of the opposite infinity. `NaN` is never approximately equal to
+    /// anything, including itself.
+    pub fn approx_eq(&self, other: &[f32], epsilon: f32) -> bool {
+        self.len == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a == b || (a - b).abs() <= epsilon)
+    }
+}
+
+/// Collects an iterator into a [`Sector`] of an explicitly chosen state.
+///
+/// Room for the lower bound of the iterator's size hint is reserved up front, the rest of the
+/// elements are pushed one by one. How the capacity ends up depends on the state, e.g. a `Tight`
+/// sector always fits its elements exactly.
+///
+/// ```rust
+/// use sector::{states::Dynamic, CollectInto, Sector};
+///
+/// let sector: Sector<Dynamic, i32> = (0..4).collect_into_sector::<Dynamic>();
+/// assert_eq!(&*sector, &[0, 1, 2, 3]);
+/// ```
+pub trait CollectInto<T>: Iterator<Item = T> + Sized {
+    fn collect_into_sector<State>(self) -> Sector<State, T>
+    where
+        Sector<State, T>: Reserve<T> + Push<T>;
+}
+
+impl<T, I: Iterator<Item = T>> CollectInto<T> for I {
+    fn collect_into_sector<State>(self) -> Sector<State, T>
+    where
+        Sector<State, T>: Reserve<T> + Push<T>,
+    {
+        let mut sector = Sector::new();
+        sector.__reserve(self.size_hint().0);
+        for elem in self {
+            sector.__push(elem);
+        }
+        sector
+    }
+}
+
+/// Resolves `range` against a sequence of length `len`, panicking like slice indexing would.
+pub(crate) fn slice_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "slice index starts at {start} but ends at {end}"
+    );
+    assert!(
+        end <= len,
+        "range end index {end} out of range for slice of length {len}"
+    );
+    start..end
+}
+
 pub(super) struct RawSec<T> {
     pub(super) ptr: NonNull<T>,
     pub(super) cap: usize,
+    /// The capacity that was requested for a zero-sized `T`.
+    ///
+    /// ZSTs never allocate, so `cap` is always `usize::MAX` for them. States with a fixed limit
+    /// (`Fixed`, `Manual`) use this instead, so `with_capacity(5)` still means 5 elements.
+    /// Unused for any other type.
+    pub(super) zst_cap: usize,
 }
 
 struct RawIter<T> {
@@ -144,22 +855,52 @@ impl<T> RawIter<T> {
     }
 }
 
+impl<T> RawIter<T> {
+    /// Drops all elements that were not yielded yet and empties the iterator.
+    ///
+    /// If one of the destructors panics, the remaining elements still get dropped.
+    fn drop_remaining(&mut self) {
+        let remaining = self.size_hint().0;
+        let start = if size_of::<T>() == 0 {
+            NonNull::dangling().as_ptr()
+        } else {
+            self.start as *mut T
+        };
+        self.start = self.end;
+
+        unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(start, remaining)) };
+    }
+}
+
 impl<T> RawSec<T> {
     fn new() -> Self {
         let (ptr, cap) = Self::create_ptr(None).unwrap();
-        RawSec { ptr, cap }
+        // No capacity was requested, so ZSTs are not limited
+        RawSec {
+            ptr,
+            cap,
+            zst_cap: !0,
+        }
     }
 
     fn with_capacity(capacity: usize) -> Self {
         let (ptr, cap) = Self::create_ptr(Some(capacity))
             .unwrap_or_else(|_| panic!("The given capacity {capacity} overflows the layout"));
-        RawSec { ptr, cap }
+        RawSec {
+            ptr,
+            cap,
+            zst_cap: capacity,
+        }
     }
 
     #[allow(dead_code)]
     fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
         let (ptr, cap) = Self::create_ptr(Some(capacity))?;
-        Ok(RawSec { ptr, cap })
+        Ok(RawSec {
+            ptr,
+            cap,
+            zst_cap: capacity,
+        })
     }
 
     /// Creates a new (_allocated_) pointer and capacity with the correct size
@@ -201,6 +942,33 @@ impl<T> Drop for RawSec<T> {
     }
 }
 
+/// Owns the buffer while [`Sector::map_into`] maps the elements in place.
+///
+/// If `f` panics, the already mapped elements and the ones not read yet get dropped before the
+/// allocation is freed.
+struct MapInPlaceGuard<T, U> {
+    buf: RawSec<T>,
+    len: usize,
+    /// Number of `T`s that were moved out of the buffer
+    read: usize,
+    /// Number of `U`s that were written to the buffer
+    written: usize,
+    _mapped: PhantomData<U>,
+}
+
+impl<T, U> Drop for MapInPlaceGuard<T, U> {
+    fn drop(&mut self) {
+        let base = self.buf.ptr.as_ptr();
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(base as *mut U, self.written));
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                base.add(self.read),
+                self.len - self.read,
+            ));
+        }
+    }
+}
+
 pub struct IntoIter<T> {
     _buf: RawSec<T>,
     iter: RawIter<T>,
@@ -226,7 +994,7 @@ impl<T> Iterator for RawIter<T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = (self.end as usize - self.start as usize) / size_of::<T>();
+        let size = (self.end as usize - self.start as usize) / cmp::max(size_of::<T>(), 1);
         (size, Some(size))
     }
 }
@@ -267,9 +1035,14 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
     }
 }
 
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+/// Keeps returning `None` once exhausted, as the start and end pointers stay equal.
+impl<T> FusedIterator for IntoIter<T> {}
+
 impl<T> Drop for IntoIter<T> {
     fn drop(&mut self) {
-        for _ in &mut *self {}
+        self.iter.drop_remaining();
     }
 }
 
@@ -289,22 +1062,255 @@ impl<State: crate::components::DefaultIter, T> IntoIterator for Sector<State, T>
     }
 }
 
+impl<'a, State, T> IntoIterator for &'a Sector<State, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<State: crate::components::DefaultDrain, T> Sector<State, T> {
+    /// Removes all elements and returns them as an iterator, front to back.
+    ///
+    /// Elements that were not consumed get dropped front to back when the iterator is dropped.
     pub fn drain(&mut self) -> Drain<'_, T> {
-        let iter = unsafe { RawIter::new(self) };
-        // Sets the len to 0 to make sure the underlying sector does not get used after free
-        self.len = 0;
+        self.drain_range(..)
+    }
+
+    /// Removes the elements in `range` and returns them as an iterator, front to back.
+    ///
+    /// When the iterator is dropped, the elements that were not consumed get dropped and the
+    /// elements after `range` are moved to close the gap. The capacity stays untouched.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds or its start is greater than its end.
+    pub fn drain_range<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let len = self.len;
+        let Range { start, end } = slice_range(range, len);
+        let iter = unsafe { RawIter::new(&self[start..end]) };
+        // Only the elements in front of the range stay reachable while the `Drain` is alive, so
+        // leaking it can not cause a use after free
+        self.len = start;
 
         Drain {
-            iter,
             sec: PhantomData,
+            base: self.buf.ptr,
+            iter,
+            tail_start: end,
+            tail_len: len - end,
+            len: &mut self.len,
+        }
+    }
+
+    /// Removes all elements and returns them as an iterator, keeping the allocation.
+    ///
+    /// This is the same as [`drain`](Self::drain), but makes explicit that the whole sector is
+    /// drained and the elements are handed out rather than dropped.
+    pub fn drain_all(&mut self) -> Drain<'_, T> {
+        self.drain()
+    }
+
+    /// Drains consecutive, non-overlapping chunks of `size` elements from the front, each as an
+    /// owned sector.
+    ///
+    /// Stops once fewer than `size` elements are left. Those stay in the sector, moved to the
+    /// front when the iterator is dropped.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is `0`.
+    pub fn drain_windows(
+        &mut self,
+        size: usize,
+    ) -> impl Iterator<Item = Sector<crate::states::Normal, T>> + '_ {
+        assert!(size != 0, "chunk size must be non-zero");
+
+        let end = self.len;
+        // The elements are only reachable through the iterator until it is dropped
+        self.len = 0;
+        DrainChunks {
+            base: self.buf.ptr,
+            len: &mut self.len,
+            start: 0,
+            end,
+            size,
+        }
+    }
+
+    /// Drops all elements and hands the allocation over to a new `Normal` sector of type `U`.
+    ///
+    /// The buffer is only reused if `U` has the same size and alignment as `T`. Otherwise the old
+    /// buffer gets freed and a fresh one with the same capacity is allocated.
+    ///
+    /// This is useful for buffer pools that recycle allocations across compatible types.
+    pub fn recycle<U>(mut self) -> Sector<crate::states::Normal, U> {
+        self.drain();
+
+        if size_of::<U>() != size_of::<T>() || mem::align_of::<U>() != mem::align_of::<T>() {
+            let capacity = if size_of::<T>() == 0 { 0 } else { self.buf.cap };
+            return Sector::with_capacity(capacity);
+        }
+
+        let buf = unsafe { ptr::read(&self.buf) };
+        mem::forget(self);
+
+        let recycled = Sector {
+            buf: RawSec {
+                ptr: buf.ptr.cast(),
+                cap: buf.cap,
+                zst_cap: buf.zst_cap,
+            },
+            len: 0,
+            min_cap: 0,
+            _state: PhantomData,
+        };
+        // The allocation is now owned by `recycled`
+        mem::forget(buf);
+        recycled
+    }
+}
+
+/// An element of a sector, returned by [`Sector::entry`].
+pub enum Entry<'a, State, T> {
+    /// The index is in bounds and refers to this element.
+    Occupied(&'a mut T),
+    /// The index is out of bounds.
+    Vacant(VacantEntry<'a, State, T>),
+}
+
+/// An index past the end of a sector, see [`Entry`].
+pub struct VacantEntry<'a, State, T> {
+    sector: &'a mut Sector<State, T>,
+    index: usize,
+}
+
+impl<'a, State, T> Entry<'a, State, T>
+where
+    Sector<State, T>: Reserve<T> + Push<T>,
+{
+    /// Returns the occupied element, or grows the sector with elements created by `f` until
+    /// the index is valid.
+    ///
+    /// `f` also fills any gap between the old end of the sector and the index.
+    pub fn or_insert_with<F: FnMut() -> T>(self, f: F) -> &'a mut T {
+        match self {
+            Entry::Occupied(elem) => elem,
+            Entry::Vacant(vacant) => vacant.insert_with(f),
+        }
+    }
+}
+
+impl<'a, State, T> VacantEntry<'a, State, T>
+where
+    Sector<State, T>: Reserve<T> + Push<T>,
+{
+    /// Returns the index of the entry.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Grows the sector with elements created by `f` until the index is valid and returns the
+    /// element at it.
+    ///
+    /// The sector grows at most once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required capacity overflows.
+    pub fn insert_with<F: FnMut() -> T>(self, mut f: F) -> &'a mut T {
+        let sector = self.sector;
+        let required = self.index.checked_add(1).expect("Capacity overflow");
+        sector.__reserve(required - sector.len);
+        while sector.len <= self.index {
+            sector.__push(f());
+        }
+        &mut sector[self.index]
+    }
+}
+
+/// Iterator returned by [`Sector::drain_windows`].
+struct DrainChunks<'a, T> {
+    base: NonNull<T>,
+    /// Length of the drained sector, set to the remainder once the iterator is dropped
+    len: &'a mut usize,
+    /// Index of the first element that was not drained yet
+    start: usize,
+    end: usize,
+    size: usize,
+}
+
+impl<T> Iterator for DrainChunks<'_, T> {
+    type Item = Sector<crate::states::Normal, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end - self.start < self.size {
+            return None;
+        }
+
+        let mut chunk: Sector<crate::states::Normal, T> = Sector::with_capacity(self.size);
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.base.as_ptr().add(self.start),
+                chunk.buf.ptr.as_ptr(),
+                self.size,
+            );
+        }
+        chunk.len = self.size;
+        self.start += self.size;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = (self.end - self.start) / self.size;
+        (chunks, Some(chunks))
+    }
+}
+
+impl<T> Drop for DrainChunks<'_, T> {
+    fn drop(&mut self) {
+        let remaining = self.end - self.start;
+        if self.start > 0 {
+            unsafe {
+                let base = self.base.as_ptr();
+                ptr::copy(base.add(self.start), base, remaining);
+            }
         }
+        *self.len = remaining;
     }
 }
 
 pub struct Drain<'a, T: 'a> {
     sec: PhantomData<&'a mut Sector<(), T>>,
+    /// Length of the drained sector, covering only the elements in front of the drained range
+    len: &'a mut usize,
+    base: NonNull<T>,
     iter: RawIter<T>,
+    /// Index of the first element after the drained range
+    tail_start: usize,
+    /// Number of elements after the drained range
+    tail_len: usize,
+}
+
+/// Moves the elements after the drained range over the gap and fixes the length.
+///
+/// This also runs if dropping one of the remaining drained elements panics.
+struct MoveTailOnDrop<'r, 'a, T>(&'r mut Drain<'a, T>);
+
+impl<T> Drop for MoveTailOnDrop<'_, '_, T> {
+    fn drop(&mut self) {
+        let drain = &mut *self.0;
+        let start = *drain.len;
+        if drain.tail_len > 0 && drain.tail_start != start {
+            unsafe {
+                let base = drain.base.as_ptr();
+                ptr::copy(base.add(drain.tail_start), base.add(start), drain.tail_len);
+            }
+        }
+        *drain.len = start + drain.tail_len;
+    }
 }
 
 impl<T> Iterator for Drain<'_, T> {
@@ -325,8 +1331,14 @@ impl<T> DoubleEndedIterator for Drain<'_, T> {
     }
 }
 
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+/// Keeps returning `None` once exhausted, as the start and end pointers stay equal.
+impl<T> FusedIterator for Drain<'_, T> {}
+
 impl<T> Drop for Drain<'_, T> {
     fn drop(&mut self) {
-        for _ in &mut *self {}
+        let guard = MoveTailOnDrop(self);
+        guard.0.iter.drop_remaining();
     }
 }
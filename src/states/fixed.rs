@@ -7,12 +7,18 @@
 //! They return a boolean value indicating success (`true`) or failure (`false`), depending on whether
 //! the operation could be performed without exceeding the fixed capacity.
 //!
-//! **Note:** There is a known conflict with zero-sized types (ZST). When using a ZST as the element type,
-//! a sector with a fixed capacity (e.g., 5) might allow unlimited insertions because ZSTs treat capacity
-//! as maximal. This behavior contradicts the intended fixed capacity semantics and is subject to further discussion.
-use core::ptr::NonNull;
-
-use crate::components::{Cap, Grow, Index, Insert, Len, Pop, Ptr, Push, Remove, Shrink};
+//! **Note:** Zero-sized types (ZST) never allocate and always report a `capacity()` of `usize::MAX`.
+//! The capacity requested with `with_capacity` is still tracked separately, so a ZST sector created with
+//! a capacity of 5 accepts exactly 5 elements. A sector created with `new` has no limit for ZSTs.
+use core::{
+    cmp,
+    ptr::{self, NonNull},
+    slice::{IterMut, SliceIndex},
+};
+
+use crate::components::{
+    Cap, Clear, Grow, Index, Insert, Len, Pop, Ptr, Push, Remove, Shrink, Truncate,
+};
 
 use crate::Sector;
 
@@ -21,17 +27,18 @@ use crate::Sector;
 /// In this state, operations that would normally trigger a growth or shrink are disabled.
 /// Instead, insertions (via `push` or `insert`) only succeed if there is enough capacity already.
 ///
-/// > **Note:** There is a known conflict with zero-sized types (ZST). If a user creates a sector
-/// > with a fixed capacity (e.g., 5) and uses a ZST as the element type, it is possible to insert or push
-/// > an unlimited number of elements because ZSTs set the capacity to its maximum value. This contradicts
-/// > the intended behavior of a fixed-capacity sector. Further discussion or resolution for this issue
-/// > is needed.
+/// > **Note:** For zero-sized types (ZST) the capacity passed to `with_capacity` is enforced even though
+/// > `capacity()` reports `usize::MAX`, as ZSTs never allocate.
 pub struct Fixed;
 
 impl crate::components::DefaultIter for Fixed {}
 
 impl crate::components::DefaultDrain for Fixed {}
 
+impl super::StateName for Fixed {
+    const NAME: &'static str = "Fixed";
+}
+
 impl<T> Sector<Fixed, T> {
     /// Attempts to push an element to the sector.
     ///
@@ -95,14 +102,197 @@ impl<T> Sector<Fixed, T> {
         self.__remove(index)
     }
 
-    /// Returns a reference to the element at the given index if it exists.
-    pub fn get(&self, index: usize) -> Option<&T> {
-        self.__get(index)
+    /// Returns a reference to an element or subslice depending on the type of index.
+    ///
+    /// - If given a position, returns a reference to the element at that position or `None` if
+    ///   out of bounds.
+    /// - If given a range, returns the subslice corresponding to that range, or `None` if out of
+    ///   bounds.
+    pub fn get<I: SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+        (**self).get(index)
+    }
+
+    /// Returns a mutable reference to an element or subslice depending on the type of index
+    /// (see [`get`](Self::get)) or `None` if the index is out of bounds.
+    pub fn get_mut<I: SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+        (**self).get_mut(index)
+    }
+
+    /// Resizes the sector to `new_len` elements, without ever exceeding the fixed capacity.
+    ///
+    /// # Behavior
+    ///
+    /// - If `new_len` is greater than the capacity, nothing changes.
+    /// - If `new_len` is less than the current length, the sector is truncated.
+    /// - Otherwise the sector is extended with clones of `value`.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the sector now has a length of `new_len`.
+    /// - `false` if `new_len` exceeds the capacity.
+    pub fn resize(&mut self, new_len: usize, value: T) -> bool
+    where
+        T: Clone,
+    {
+        if new_len > self.__cap() {
+            return false;
+        }
+
+        if new_len < self.__len() {
+            self.__truncate(new_len);
+        } else {
+            while self.__len() < new_len {
+                self.__push(value.clone());
+            }
+        }
+        true
+    }
+
+    /// Drops all elements, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.__clear();
+    }
+
+    /// Copies as many elements of `other` to the end of the sector as the capacity allows.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements that were copied, starting from the front of `other`.
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> usize
+    where
+        T: Copy,
+    {
+        let len = self.__len();
+        let count = cmp::min(other.len(), self.__cap() - len);
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), self.__ptr().as_ptr().add(len), count);
+        }
+        self.__len_set(len + count);
+        count
+    }
+
+    /// Returns an iterator over mutable references to all elements.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        (**self).iter_mut()
+    }
+
+    /// Shortens the sector to `new_len` elements, dropping the rest.
+    ///
+    /// Unlike a resize this can never grow the sector: if `new_len` is greater or equal to the
+    /// current length, nothing happens.
+    pub fn shorten_to(&mut self, new_len: usize) {
+        self.__truncate(new_len);
+    }
+
+    /// Does nothing, as the capacity of a `Fixed` sector never changes.
+    ///
+    /// Exists so memory can be reclaimed through the same method on every state.
+    pub fn compact(&mut self) {}
+
+    /// Returns the elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    /// Returns the elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    /// Returns `true` if the sector contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        (**self).contains(x)
+    }
+
+    /// Binary searches the sorted sector for `x`.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index where `x` could
+    /// be inserted while keeping the order. See [`slice::binary_search`] for details.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        (**self).binary_search(x)
     }
 
-    /// Returns a mutable reference to the element at the given index if it exists.
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        self.__get_mut(index)
+    /// Returns the index of the first element for which `pred` returns `false`.
+    ///
+    /// The sector has to be partitioned by `pred`, i.e. all elements it accepts come first. See
+    /// [`slice::partition_point`] for details.
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, pred: F) -> usize {
+        (**self).partition_point(pred)
+    }
+
+    /// Drops elements from the back as long as `pred` returns `true` for the last one.
+    ///
+    /// The matching elements are removed at once, the capacity stays unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The number of removed elements.
+    pub fn pop_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+        let count = self.iter().rev().take_while(|elem| pred(elem)).count();
+        self.__truncate(self.__len() - count);
+        count
+    }
+
+    /// Caps the sector at `max_len` elements, dropping the excess tail.
+    ///
+    /// Does nothing if the sector already holds at most `max_len` elements.
+    pub fn clamp_len(&mut self, max_len: usize) {
+        self.__truncate(max_len);
+    }
+
+    /// Overwrites every element with clones of `value`, dropping the old elements.
+    ///
+    /// Length and capacity stay the same.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        (**self).fill(value);
+    }
+
+    /// Overwrites every element with the values returned by `f`, dropping the old elements.
+    ///
+    /// Length and capacity stay the same.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        (**self).fill_with(f);
+    }
+
+    /// Rotates the elements in place so the element at `mid` becomes the first one.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `mid` is greater than the length.
+    pub fn rotate_left(&mut self, mid: usize) {
+        (**self).rotate_left(mid);
+    }
+
+    /// Rotates the elements in place so the last `k` elements move to the front.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `k` is greater than the length.
+    pub fn rotate_right(&mut self, k: usize) {
+        (**self).rotate_right(k);
+    }
+
+    /// Reverses the order of the elements in place.
+    pub fn reverse(&mut self) {
+        (**self).reverse();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Sector<Fixed, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
@@ -146,8 +336,16 @@ impl<T> Cap for Sector<Fixed, T> {
     /// Returns the current capacity of the sector.
     ///
     /// This value indicates how many elements the sector can hold without needing to grow.
+    ///
+    /// For zero-sized types this is the capacity the sector was created with rather than the
+    /// allocation's `usize::MAX`. It never reports less than the length, which can happen after a
+    /// transition from a growing state.
     fn __cap(&self) -> usize {
-        self.capacity()
+        if size_of::<T>() == 0 {
+            cmp::max(self.buf.zst_cap, self.len)
+        } else {
+            self.capacity()
+        }
     }
 
     /// Sets a new capacity for the sector.
@@ -182,6 +380,8 @@ impl<T> Pop<T> for Sector<Fixed, T> {}
 impl<T> Insert<T> for Sector<Fixed, T> {}
 impl<T> Index<T> for Sector<Fixed, T> {}
 impl<T> Remove<T> for Sector<Fixed, T> {}
+impl<T> Truncate<T> for Sector<Fixed, T> {}
+impl<T> Clear<T> for Sector<Fixed, T> {}
 
 #[cfg(test)]
 mod tests {
@@ -660,7 +860,7 @@ mod tests {
         let mut sector: Sector<Fixed, i32> = Sector::with_capacity(5);
 
         for i in 0..5 {
-            let _ = sector.push(i);
+            assert_eq!(sector.push(i), Ok(()));
         }
 
         let mut drain_iter = sector.drain();
@@ -767,4 +967,270 @@ mod tests {
         assert_eq!(sector.capacity(), 1000);
         assert_eq!(sector.len(), 0)
     }
+
+    #[test]
+    fn test_resize_within_capacity() {
+        let mut sector: Sector<Fixed, i32> = Sector::with_capacity(5);
+        assert_eq!(sector.push(1), Ok(()));
+
+        assert!(sector.resize(3, 7));
+        assert_eq!(&*sector, &[1, 7, 7]);
+
+        assert!(sector.resize(2, 0));
+        assert_eq!(&*sector, &[1, 7]);
+        assert_eq!(sector.capacity(), 5);
+    }
+
+    #[test]
+    fn test_resize_to_capacity() {
+        let mut sector: Sector<Fixed, i32> = Sector::with_capacity(4);
+
+        assert!(sector.resize(4, 2));
+        assert_eq!(&*sector, &[2, 2, 2, 2]);
+        assert_eq!(sector.push(3), Err(3));
+    }
+
+    #[test]
+    fn test_resize_beyond_capacity() {
+        let mut sector: Sector<Fixed, i32> = Sector::with_capacity(4);
+        assert_eq!(sector.push(1), Ok(()));
+
+        assert!(!sector.resize(5, 2));
+        assert_eq!(&*sector, &[1]);
+        assert_eq!(sector.capacity(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 5) should be <= len (is 3)")]
+    fn test_insert_past_end() {
+        let mut sector: Sector<Fixed, i32> = Sector::with_capacity(10);
+        repeat!(assert_eq!(sector.push(1), Ok(())), 3);
+
+        let _ = sector.insert(5, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index (is 3) should be < len (is 3)")]
+    fn test_remove_out_of_bounds() {
+        let mut sector: Sector<Fixed, i32> = Sector::with_capacity(10);
+        repeat!(assert_eq!(sector.push(1), Ok(())), 3);
+
+        sector.remove(3);
+    }
+
+    #[test]
+    fn test_clear() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Fixed, DropCounter> = Sector::with_capacity(8);
+        repeat!(
+            assert!(sector.push(DropCounter { counter: &counter }).is_ok()),
+            8
+        );
+        let cap = sector.capacity();
+
+        sector.clear();
+        assert_eq!(counter.get(), 8);
+        assert_eq!(sector.len(), 0);
+        assert_eq!(sector.capacity(), cap);
+    }
+
+    #[test]
+    fn test_clear_zst() {
+        let mut sector: Sector<Fixed, ZeroSizedType> = Sector::new();
+        repeat!(assert!(sector.push(ZeroSizedType).is_ok()), 5);
+
+        sector.clear();
+        assert_eq!(sector.len(), 0);
+        assert_eq!(sector.capacity(), usize::MAX);
+    }
+
+    #[test]
+    fn test_try_extend_from_slice() {
+        let mut sector: Sector<Fixed, i32> = Sector::with_capacity(4);
+        assert_eq!(sector.push(0), Ok(()));
+
+        assert_eq!(sector.try_extend_from_slice(&[1, 2]), 2);
+        assert_eq!(sector.try_extend_from_slice(&[3, 4, 5]), 1);
+        assert_eq!(sector.try_extend_from_slice(&[6]), 0);
+        assert_eq!(&*sector, &[0, 1, 2, 3]);
+        assert_eq!(sector.capacity(), 4);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut sector: Sector<Fixed, i32> = Sector::with_capacity(3);
+        for i in 1..=3 {
+            assert_eq!(sector.push(i), Ok(()));
+        }
+
+        for elem in sector.iter_mut() {
+            *elem *= 10;
+        }
+        assert_eq!(&*sector, &[10, 20, 30]);
+    }
+
+    #[test]
+    fn test_shorten_to() {
+        let mut sector: Sector<Fixed, i32> = Sector::with_capacity(5);
+        for i in 0..5 {
+            assert_eq!(sector.push(i), Ok(()));
+        }
+
+        sector.shorten_to(7);
+        assert_eq!(sector.len(), 5);
+        sector.shorten_to(5);
+        assert_eq!(sector.len(), 5);
+
+        sector.shorten_to(2);
+        assert_eq!(&*sector, &[0, 1]);
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut sector: Sector<Fixed, i32> = Sector::with_capacity(16);
+        for i in 0..4 {
+            assert_eq!(sector.push(i), Ok(()));
+        }
+
+        sector.compact();
+        assert_eq!(sector.capacity(), 16);
+        assert_eq!(&*sector, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let mut sector: Sector<Fixed, i32> = Sector::from([3, 1, 2]);
+
+        sector.as_mut_slice().sort();
+        assert_eq!(sector.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_push_zst_capacity_limit() {
+        let mut sector: Sector<Fixed, ZeroSizedType> = Sector::with_capacity(5);
+
+        repeat!(assert_eq!(sector.push(ZeroSizedType), Ok(())), 5);
+
+        assert_eq!(sector.push(ZeroSizedType), Err(ZeroSizedType));
+        assert_eq!(sector.insert(0, ZeroSizedType), Err(ZeroSizedType));
+        assert_eq!(sector.len(), 5);
+        assert_eq!(sector.capacity(), usize::MAX);
+
+        assert_eq!(sector.pop(), Some(ZeroSizedType));
+        assert_eq!(sector.push(ZeroSizedType), Ok(()));
+    }
+
+    #[test]
+    fn test_contains_and_binary_search() {
+        let sector: Sector<Fixed, i32> = Sector::from([1, 3, 5, 7]);
+
+        assert!(sector.contains(&5));
+        assert!(!sector.contains(&4));
+        assert_eq!(sector.binary_search(&7), Ok(3));
+        assert_eq!(sector.binary_search(&4), Err(2));
+        assert_eq!(sector.binary_search(&0), Err(0));
+    }
+
+    #[test]
+    fn test_swap_sectors() {
+        let mut a: Sector<Fixed, i32> = Sector::from([1, 2, 3]);
+        let mut b: Sector<Fixed, i32> = Sector::from([4]);
+        let (ptr_a, cap_a) = (unsafe { a.as_ptr() }, a.capacity());
+        let (ptr_b, cap_b) = (unsafe { b.as_ptr() }, b.capacity());
+
+        a.swap_sectors(&mut b);
+
+        assert_eq!(&*a, &[4]);
+        assert_eq!(&*b, &[1, 2, 3]);
+        assert_eq!(unsafe { a.as_ptr() }, ptr_b);
+        assert_eq!(unsafe { b.as_ptr() }, ptr_a);
+        assert_eq!((a.capacity(), b.capacity()), (cap_b, cap_a));
+    }
+
+    #[test]
+    fn test_into_iter_by_reference() {
+        let mut sector: Sector<Fixed, i32> = Sector::from([1, 2, 3]);
+
+        for elem in &mut sector {
+            *elem *= 10;
+        }
+
+        let mut sum = 0;
+        for elem in &sector {
+            sum += elem;
+        }
+        assert_eq!(sum, 60);
+    }
+
+    #[test]
+    fn test_pop_while() {
+        let mut sector: Sector<Fixed, i32> = Sector::from([1, 2, 0, 0, 0]);
+
+        assert_eq!(sector.pop_while(|x| *x == 0), 3);
+        assert_eq!(&*sector, &[1, 2]);
+
+        assert_eq!(sector.pop_while(|x| *x == 0), 0);
+        assert_eq!(sector.pop_while(|_| true), 2);
+        assert!(sector.is_empty());
+    }
+
+    #[test]
+    fn test_clamp_len() {
+        let mut sector: Sector<Fixed, i32> = Sector::from([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        sector.clamp_len(20);
+        assert_eq!(sector.len(), 10);
+
+        sector.clamp_len(5);
+        assert_eq!(&*sector, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut sector: Sector<Fixed, i32> = Sector::from([1, 2, 3]);
+        let cap = sector.capacity();
+
+        sector.fill(7);
+        assert_eq!(&*sector, &[7, 7, 7]);
+
+        let mut next = 0;
+        sector.fill_with(|| {
+            next += 1;
+            next
+        });
+        assert_eq!(&*sector, &[1, 2, 3]);
+        assert_eq!(sector.capacity(), cap);
+
+        let mut empty: Sector<Fixed, i32> = Sector::new();
+        empty.fill(1);
+        empty.fill_with(|| unreachable!());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut sector: Sector<Fixed, i32> = Sector::from([1, 2, 3, 4, 5]);
+
+        sector.rotate_left(2);
+        assert_eq!(&*sector, &[3, 4, 5, 1, 2]);
+        sector.rotate_right(3);
+        assert_eq!(&*sector, &[5, 1, 2, 3, 4]);
+        sector.rotate_left(5);
+        assert_eq!(&*sector, &[5, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_out_of_bounds() {
+        let mut sector: Sector<Fixed, i32> = Sector::from([1, 2, 3, 4, 5]);
+        sector.rotate_right(6);
+    }
+
+    #[test]
+    fn test_partition_point() {
+        let sector: Sector<Fixed, i32> = Sector::from([1, 2, 3, 7, 8]);
+        assert_eq!(sector.partition_point(|x| *x < 5), 3);
+        assert_eq!(sector.partition_point(|x| *x < 0), 0);
+        assert_eq!(sector.partition_point(|x| *x < 10), 5);
+    }
 }
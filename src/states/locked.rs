@@ -14,7 +14,10 @@
 //!
 //! All other operations (such as element access, insertion, and removal) behave as defined by their
 //! respective traits and do not have unique documentation for the `Locked` state.
-use core::ptr::NonNull;
+use core::{
+    ptr::NonNull,
+    slice::{Iter, SliceIndex},
+};
 
 use crate::components::{Cap, Grow, Index, Insert, Len, Pop, Ptr, Push, Remove, Shrink};
 
@@ -26,15 +29,67 @@ impl crate::components::DefaultIter for Locked {}
 
 impl crate::components::DefaultDrain for Locked {}
 
+impl super::StateName for Locked {
+    const NAME: &'static str = "Locked";
+}
+
 impl<T> Sector<Locked, T> {
-    /// Returns a reference to the element at the given index if it exists.
-    pub fn get(&self, index: usize) -> Option<&T> {
-        self.__get(index)
+    /// Returns a reference to an element or subslice depending on the type of index.
+    ///
+    /// - If given a position, returns a reference to the element at that position or `None` if
+    ///   out of bounds.
+    /// - If given a range, returns the subslice corresponding to that range, or `None` if out of
+    ///   bounds.
+    pub fn get<I: SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+        (**self).get(index)
+    }
+
+    /// Returns a mutable reference to an element or subslice depending on the type of index
+    /// (see [`get`](Self::get)) or `None` if the index is out of bounds.
+    pub fn get_mut<I: SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+        (**self).get_mut(index)
     }
 
-    /// Returns a mutable reference to the element at the given index if it exists.
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        self.__get_mut(index)
+    /// Returns an iterator over references to all elements.
+    pub fn iter(&self) -> Iter<'_, T> {
+        (**self).iter()
+    }
+
+    /// Does nothing, as the capacity of a `Locked` sector never changes.
+    ///
+    /// Exists so memory can be reclaimed through the same method on every state.
+    pub fn compact(&mut self) {}
+
+    /// Returns the elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    /// Returns `true` if the sector contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        (**self).contains(x)
+    }
+
+    /// Binary searches the sorted sector for `x`.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index where `x` could
+    /// be inserted while keeping the order. See [`slice::binary_search`] for details.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        (**self).binary_search(x)
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`.
+    ///
+    /// The sector has to be partitioned by `pred`, i.e. all elements it accepts come first. See
+    /// [`slice::partition_point`] for details.
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, pred: F) -> usize {
+        (**self).partition_point(pred)
     }
 }
 
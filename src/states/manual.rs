@@ -10,9 +10,16 @@
 //!
 //! - **grow:** Manually increases the sector's capacity by a specified amount.
 //! - **shrink:** Manually decreases the sector's capacity by a specified amount.
-use core::ptr::NonNull;
+use core::{
+    cmp,
+    ops::RangeBounds,
+    ptr::{self, NonNull},
+    slice::{IterMut, SliceIndex},
+};
 
-use crate::components::{Cap, Grow, Index, Insert, Len, Pop, Ptr, Push, Remove, Shrink};
+use crate::components::{
+    Cap, Clear, Grow, Index, Insert, Len, Pop, Ptr, Push, Remove, Retain, Shrink, ShrinkTo,
+};
 
 use crate::Sector;
 
@@ -22,6 +29,10 @@ impl crate::components::DefaultIter for Manual {}
 
 impl crate::components::DefaultDrain for Manual {}
 
+impl super::StateName for Manual {
+    const NAME: &'static str = "Manual";
+}
+
 impl<T> Sector<Manual, T> {
     /// Attempts to push an element to the sector.
     ///
@@ -85,14 +96,20 @@ impl<T> Sector<Manual, T> {
         self.__remove(index)
     }
 
-    /// Returns a reference to the element at the given index if it exists.
-    pub fn get(&self, index: usize) -> Option<&T> {
-        self.__get(index)
+    /// Returns a reference to an element or subslice depending on the type of index.
+    ///
+    /// - If given a position, returns a reference to the element at that position or `None` if
+    ///   out of bounds.
+    /// - If given a range, returns the subslice corresponding to that range, or `None` if out of
+    ///   bounds.
+    pub fn get<I: SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+        (**self).get(index)
     }
 
-    /// Returns a mutable reference to the element at the given index if it exists.
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        self.__get_mut(index)
+    /// Returns a mutable reference to an element or subslice depending on the type of index
+    /// (see [`get`](Self::get)) or `None` if the index is out of bounds.
+    pub fn get_mut<I: SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+        (**self).get_mut(index)
     }
 
     /// Attempts to manually grow the sector's capacity by the specified amount.
@@ -103,15 +120,22 @@ impl<T> Sector<Manual, T> {
     ///
     /// # Behavior
     ///
-    /// - If the requested growth amount (`cap_to_grow`) is `0`, if the type `T` is zero-sized, or if the
-    ///   current capacity is at its maximum (`isize::MAX`), no growth is performed and the function returns `0`.
+    /// - If the requested growth amount (`cap_to_grow`) is `0`, or if the current capacity is at its
+    ///   maximum (`isize::MAX`), no growth is performed and the function returns `0`.
+    /// - For zero-sized types only the enforced capacity is raised, saturating at `usize::MAX`, and
+    ///   the amount it actually grew by is returned.
     /// - Otherwise, the function calculates the target capacity increase and attempts to perform a manual
     ///   growth operation.
     /// - If the manual growth operation succeeds, the function returns the requested grow amount.
     /// - If the operation fails, it returns `0`.
     pub fn grow(&mut self, cap_to_grow: usize) -> usize {
-        // TODO: Is this enough zst handling?
-        if cap_to_grow == 0 || size_of::<T>() == 0 || self.__cap() >= isize::MAX as usize {
+        if size_of::<T>() == 0 {
+            let cap = self.__cap();
+            let new_cap = cap.saturating_add(cap_to_grow);
+            self.buf.zst_cap = new_cap;
+            return new_cap - cap;
+        }
+        if cap_to_grow == 0 || self.__cap() >= isize::MAX as usize {
             return 0;
         }
 
@@ -127,6 +151,29 @@ impl<T> Sector<Manual, T> {
         }
     }
 
+    /// Grows the sector so it can hold exactly `total_capacity` elements.
+    ///
+    /// Does nothing if the capacity already is `total_capacity` or more. For zero-sized types
+    /// only the enforced capacity gets raised, as they never allocate.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the sector can hold `total_capacity` elements afterwards, `false` if the
+    /// allocation failed or `total_capacity` overflows the layout. The sector stays untouched in
+    /// that case.
+    pub fn reserve_exact(&mut self, total_capacity: usize) -> bool {
+        let cap = self.__cap();
+        if total_capacity <= cap {
+            return true;
+        }
+        if size_of::<T>() == 0 {
+            self.buf.zst_cap = total_capacity;
+            return true;
+        }
+
+        self.__try_grow_manually(total_capacity - cap).is_ok()
+    }
+
     /// Attempts to manually shrink the sector's capacity by the specified amount.
     ///
     /// # Returns
@@ -135,16 +182,20 @@ impl<T> Sector<Manual, T> {
     ///
     /// # Behavior
     ///
-    /// - If the requested shrink amount (`cap_to_shrink`) is `0`, if the type `T` is zero-sized, or if the
-    ///   current capacity is `0`, no shrinking is performed and the function returns `0`.
+    /// - If the requested shrink amount (`cap_to_shrink`) is `0`, or if the current capacity is `0`, no
+    ///   shrinking is performed and the function returns `0`.
     /// - The function calculates the new capacity by subtracting `cap_to_shrink` from the current capacity.
     /// - If the new capacity is less than the current number of elements, elements beyond the new capacity
     ///   are dropped, and the sector's length is adjusted accordingly.
-    /// - The function then attempts to perform the manual shrink operation.
+    /// - For zero-sized types only the enforced capacity is lowered, as they never allocate. A
+    ///   sector created with `new` has no limit and stays that way.
+    /// - Otherwise the function attempts to perform the manual shrink operation.
     /// - If the operation is successful, the function returns the shrink factor; otherwise, it returns `0`.
     pub fn shrink(&mut self, cap_to_shrink: usize) -> usize {
-        // TODO: Is this enough zst handling?
-        if cap_to_shrink == 0 || size_of::<T>() == 0 || self.__cap() == 0 {
+        if cap_to_shrink == 0 || self.__cap() == 0 {
+            return 0;
+        }
+        if size_of::<T>() == 0 && self.__cap() == usize::MAX {
             return 0;
         }
 
@@ -154,17 +205,213 @@ impl<T> Sector<Manual, T> {
         };
 
         let new_cap = self.__cap() - shrink_factor;
-        if new_cap < self.__len() {
-            for i in new_cap..self.__len() {
-                unsafe { self.__ptr().add(i).drop_in_place() };
-            }
+        let len = self.__len();
+        if new_cap < len {
+            // The length gets set first, so a panicking `drop` can not cause a double drop
             self.__len_set(new_cap);
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    self.__ptr().as_ptr().add(new_cap),
+                    len - new_cap,
+                ));
+            }
+        }
+        if size_of::<T>() == 0 {
+            self.buf.zst_cap = new_cap;
+            return shrink_factor;
         }
         match self.__try_shrink_manually(shrink_factor) {
             Ok(_) => shrink_factor,
             Err(_) => 0,
         }
     }
+
+    /// Drops all elements, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.__clear();
+    }
+
+    /// Copies as many elements of `other` to the end of the sector as the capacity allows.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements that were copied, starting from the front of `other`.
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> usize
+    where
+        T: Copy,
+    {
+        let len = self.__len();
+        let count = cmp::min(other.len(), self.__cap() - len);
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), self.__ptr().as_ptr().add(len), count);
+        }
+        self.__len_set(len + count);
+        count
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving their order.
+    ///
+    /// The capacity stays untouched; use [`shrink`](Self::shrink) to release the freed space.
+    ///
+    /// # Returns
+    ///
+    /// The number of removed elements.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> usize {
+        self.__retain_mut(|elem| f(elem))
+    }
+
+    /// Keeps only the elements within `range` for which `f` returns `true`, leaving the elements
+    /// outside of `range` untouched.
+    ///
+    /// The capacity stays untouched; use [`shrink`](Self::shrink) to release the freed space.
+    ///
+    /// # Returns
+    ///
+    /// The number of removed elements.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds or its start is greater than its end.
+    pub fn retain_range<R: RangeBounds<usize>, F: FnMut(&T) -> bool>(
+        &mut self,
+        range: R,
+        mut f: F,
+    ) -> usize {
+        self.__retain_range_mut(range, |elem| f(elem))
+    }
+
+    /// Drops all elements so the sector can be reused, e.g. from an object pool.
+    ///
+    /// Unlike dropping and recreating the sector, the capacity is guaranteed to be retained, so
+    /// refilling it up to the previous length does not reallocate.
+    pub fn reset(&mut self) {
+        let capacity = self.__cap();
+        self.__clear();
+        debug_assert_eq!(self.__cap(), capacity, "reset must retain the capacity");
+    }
+
+    /// Returns an iterator over mutable references to all elements.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        (**self).iter_mut()
+    }
+
+    /// Shrinks the capacity to `max(len, min_capacity)`, keeping some headroom for future
+    /// elements.
+    ///
+    /// Does nothing if `min_capacity` is greater or equal to the current capacity.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.__shrink_to(min_capacity);
+    }
+
+    /// Shrinks the capacity to exactly the length. No element gets dropped.
+    ///
+    /// Does nothing for zero-sized types or if there is no spare capacity.
+    ///
+    /// # Returns
+    ///
+    /// The number of freed slots, or `0` if nothing was freed because the reallocation failed.
+    pub fn shrink_to_fit(&mut self) -> usize {
+        let excess = self.__cap() - self.__len();
+        if excess == 0 || size_of::<T>() == 0 {
+            return 0;
+        }
+
+        match self.__try_shrink_manually(excess) {
+            Ok(_) => excess,
+            Err(_) => 0,
+        }
+    }
+
+    /// Reclaims unused memory by shrinking the capacity to exactly the length.
+    pub fn compact(&mut self) {
+        self.__shrink_to_fit();
+    }
+
+    /// Returns the elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    /// Returns the elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    /// Returns `true` if the sector contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        (**self).contains(x)
+    }
+
+    /// Binary searches the sorted sector for `x`.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index where `x` could
+    /// be inserted while keeping the order. See [`slice::binary_search`] for details.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        (**self).binary_search(x)
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`.
+    ///
+    /// The sector has to be partitioned by `pred`, i.e. all elements it accepts come first. See
+    /// [`slice::partition_point`] for details.
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, pred: F) -> usize {
+        (**self).partition_point(pred)
+    }
+
+    /// Overwrites every element with clones of `value`, dropping the old elements.
+    ///
+    /// Length and capacity stay the same.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        (**self).fill(value);
+    }
+
+    /// Overwrites every element with the values returned by `f`, dropping the old elements.
+    ///
+    /// Length and capacity stay the same.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        (**self).fill_with(f);
+    }
+
+    /// Rotates the elements in place so the element at `mid` becomes the first one.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `mid` is greater than the length.
+    pub fn rotate_left(&mut self, mid: usize) {
+        (**self).rotate_left(mid);
+    }
+
+    /// Rotates the elements in place so the last `k` elements move to the front.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `k` is greater than the length.
+    pub fn rotate_right(&mut self, k: usize) {
+        (**self).rotate_right(k);
+    }
+
+    /// Reverses the order of the elements in place.
+    pub fn reverse(&mut self) {
+        (**self).reverse();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Sector<Manual, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
 }
 
 impl<T> Ptr<T> for Sector<Manual, T> {
@@ -207,8 +454,16 @@ impl<T> Cap for Sector<Manual, T> {
     /// Returns the current capacity of the sector.
     ///
     /// This value indicates how many elements the sector can hold without needing to grow.
+    ///
+    /// For zero-sized types this is the capacity the sector was created with rather than the
+    /// allocation's `usize::MAX`. It never reports less than the length, which can happen after a
+    /// transition from a growing state.
     fn __cap(&self) -> usize {
-        self.capacity()
+        if size_of::<T>() == 0 {
+            cmp::max(self.buf.zst_cap, self.len)
+        } else {
+            self.capacity()
+        }
     }
 
     /// Sets a new capacity for the sector.
@@ -245,6 +500,9 @@ impl<T> Pop<T> for Sector<Manual, T> {}
 impl<T> Insert<T> for Sector<Manual, T> {}
 impl<T> Index<T> for Sector<Manual, T> {}
 impl<T> Remove<T> for Sector<Manual, T> {}
+impl<T> Clear<T> for Sector<Manual, T> {}
+impl<T> Retain<T> for Sector<Manual, T> {}
+impl<T> ShrinkTo<T> for Sector<Manual, T> {}
 
 #[cfg(test)]
 mod tests {
@@ -700,7 +958,7 @@ mod tests {
         let mut sector: Sector<Manual, i32> = Sector::with_capacity(5);
 
         for i in 0..5 {
-            let _ = sector.push(i);
+            assert_eq!(sector.push(i), Ok(()));
         }
 
         let mut drain_iter = sector.drain();
@@ -818,6 +1076,27 @@ mod tests {
         assert!(sector.capacity() == !0);
     }
 
+    #[test]
+    fn test_grow_and_shrink_zst() {
+        let mut sector: Sector<Manual, ZeroSizedType> = Sector::with_capacity(1);
+
+        assert_eq!(sector.grow(5), 5);
+        repeat!(assert_eq!(sector.push(ZeroSizedType), Ok(())), 6);
+        assert_eq!(sector.push(ZeroSizedType), Err(ZeroSizedType));
+
+        assert_eq!(sector.shrink(2), 2);
+        assert_eq!(sector.len(), 4);
+        assert_eq!(sector.push(ZeroSizedType), Err(ZeroSizedType));
+
+        assert_eq!(sector.shrink(10), 4);
+        assert!(sector.is_empty());
+        assert_eq!(sector.push(ZeroSizedType), Err(ZeroSizedType));
+
+        // Without a limit there is nothing left to grow
+        let mut unlimited: Sector<Manual, ZeroSizedType> = Sector::new();
+        assert_eq!(unlimited.grow(5), 0);
+    }
+
     #[test]
     fn test_behaviour_shrink_1() {
         let mut sector: Sector<Manual, i32> = Sector::with_capacity(1000);
@@ -942,4 +1221,400 @@ mod tests {
 
         assert_eq!(sector.capacity(), 0);
     }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 5) should be <= len (is 3)")]
+    fn test_insert_past_end() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(10);
+        repeat!(assert_eq!(sector.push(1), Ok(())), 3);
+
+        let _ = sector.insert(5, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index (is 3) should be < len (is 3)")]
+    fn test_remove_out_of_bounds() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(10);
+        repeat!(assert_eq!(sector.push(1), Ok(())), 3);
+
+        sector.remove(3);
+    }
+
+    #[test]
+    fn test_shrink_to_zero_drops_elements() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Manual, DropCounter> = Sector::with_capacity(4);
+        repeat!(
+            assert!(sector.push(DropCounter { counter: &counter }).is_ok()),
+            3
+        );
+
+        assert_eq!(sector.shrink(4), 4);
+        assert_eq!(counter.get(), 3);
+        assert_eq!(sector.len(), 0);
+        assert_eq!(sector.capacity(), 0);
+
+        // The sector is still usable after its allocation was freed
+        assert_eq!(sector.grow(1), 1);
+        assert!(sector.push(DropCounter { counter: &counter }).is_ok());
+        drop(sector);
+        assert_eq!(counter.get(), 4);
+    }
+
+    #[test]
+    fn test_shrink_below_len_drops_tail() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(5);
+        for i in 0..5 {
+            assert_eq!(sector.push(i), Ok(()));
+        }
+
+        assert_eq!(sector.shrink(3), 3);
+        assert_eq!(&*sector, &[0, 1]);
+        assert_eq!(sector.capacity(), 2);
+    }
+
+    #[test]
+    fn test_shrink_more_than_capacity() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(3);
+        assert_eq!(sector.push(1), Ok(()));
+
+        assert_eq!(sector.shrink(10), 3);
+        assert_eq!(sector.capacity(), 0);
+        assert!(sector.is_empty());
+
+        assert_eq!(sector.shrink(1), 0);
+    }
+
+    #[test]
+    fn test_clear() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Manual, DropCounter> = Sector::with_capacity(8);
+        repeat!(
+            assert!(sector.push(DropCounter { counter: &counter }).is_ok()),
+            8
+        );
+        let cap = sector.capacity();
+
+        sector.clear();
+        assert_eq!(counter.get(), 8);
+        assert_eq!(sector.len(), 0);
+        assert_eq!(sector.capacity(), cap);
+    }
+
+    #[test]
+    fn test_clear_zst() {
+        let mut sector: Sector<Manual, ZeroSizedType> = Sector::new();
+        repeat!(assert!(sector.push(ZeroSizedType).is_ok()), 5);
+
+        sector.clear();
+        assert_eq!(sector.len(), 0);
+        assert_eq!(sector.capacity(), usize::MAX);
+    }
+
+    #[test]
+    fn test_try_extend_from_slice() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(4);
+        assert_eq!(sector.push(0), Ok(()));
+
+        assert_eq!(sector.try_extend_from_slice(&[1, 2]), 2);
+        assert_eq!(sector.try_extend_from_slice(&[3, 4, 5]), 1);
+        assert_eq!(sector.try_extend_from_slice(&[6]), 0);
+        assert_eq!(&*sector, &[0, 1, 2, 3]);
+        assert_eq!(sector.capacity(), 4);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(10);
+        for i in 0..10 {
+            assert_eq!(sector.push(i), Ok(()));
+        }
+
+        assert_eq!(sector.retain(|x| x % 3 == 0), 6);
+        assert_eq!(&*sector, &[0, 3, 6, 9]);
+        assert_eq!(sector.capacity(), 10);
+
+        assert_eq!(sector.retain(|_| true), 0);
+        assert_eq!(sector.len(), 4);
+    }
+
+    #[test]
+    fn test_retain_drops_removed() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Manual, DropCounter> = Sector::with_capacity(6);
+        repeat!(
+            assert!(sector.push(DropCounter { counter: &counter }).is_ok()),
+            6
+        );
+
+        let mut keep = false;
+        assert_eq!(
+            sector.retain(|_| {
+                keep = !keep;
+                keep
+            }),
+            3
+        );
+        assert_eq!(counter.get(), 3);
+        assert_eq!(sector.len(), 3);
+    }
+
+    #[test]
+    fn test_reset_pool_cycle() {
+        let mut pooled: Sector<Manual, i32> = Sector::with_capacity(16);
+        let ptr = pooled.__ptr();
+
+        for cycle in 0..3 {
+            for i in 0..16 {
+                assert_eq!(pooled.push(cycle * 16 + i), Ok(()));
+            }
+            assert_eq!(pooled.len(), 16);
+            assert_eq!(pooled[0], cycle * 16);
+
+            pooled.reset();
+            assert!(pooled.is_empty());
+            assert_eq!(pooled.capacity(), 16);
+            assert_eq!(pooled.__ptr(), ptr);
+        }
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(3);
+        for i in 1..=3 {
+            assert_eq!(sector.push(i), Ok(()));
+        }
+
+        for elem in sector.iter_mut() {
+            *elem *= 10;
+        }
+        assert_eq!(&*sector, &[10, 20, 30]);
+    }
+
+    #[test]
+    fn test_shrink_to() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(100);
+        for i in 0..10 {
+            assert_eq!(sector.push(i), Ok(()));
+        }
+
+        sector.shrink_to(200);
+        assert_eq!(sector.capacity(), 100);
+
+        sector.shrink_to(50);
+        assert_eq!(sector.capacity(), 50);
+
+        sector.shrink_to(2);
+        assert_eq!(sector.capacity(), 10);
+        assert_eq!(sector.len(), 10);
+    }
+
+    #[test]
+    fn test_shrink_to_empty_deallocates() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(8);
+
+        sector.shrink_to(0);
+        assert_eq!(sector.capacity(), 0);
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(16);
+        for i in 0..4 {
+            assert_eq!(sector.push(i), Ok(()));
+        }
+
+        sector.compact();
+        assert_eq!(sector.capacity(), 4);
+        assert_eq!(&*sector, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let mut sector: Sector<Manual, i32> = Sector::from([3, 1, 2]);
+
+        sector.as_mut_slice().sort();
+        assert_eq!(sector.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_push_zst_capacity_limit() {
+        let mut sector: Sector<Manual, ZeroSizedType> = Sector::with_capacity(5);
+
+        repeat!(assert_eq!(sector.push(ZeroSizedType), Ok(())), 5);
+
+        assert_eq!(sector.push(ZeroSizedType), Err(ZeroSizedType));
+        assert_eq!(sector.insert(0, ZeroSizedType), Err(ZeroSizedType));
+        assert_eq!(sector.len(), 5);
+        assert_eq!(sector.capacity(), usize::MAX);
+
+        assert_eq!(sector.pop(), Some(ZeroSizedType));
+        assert_eq!(sector.push(ZeroSizedType), Ok(()));
+    }
+
+    #[test]
+    fn test_retain_range() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(10);
+        for i in 0..10 {
+            assert_eq!(sector.push(i), Ok(()));
+        }
+
+        assert_eq!(sector.retain_range(2..6, |x| x % 2 == 0), 2);
+        assert_eq!(&*sector, &[0, 1, 2, 4, 6, 7, 8, 9]);
+        assert_eq!(sector.capacity(), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 11 out of range for slice of length 10")]
+    fn test_retain_range_out_of_bounds() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(10);
+        for i in 0..10 {
+            assert_eq!(sector.push(i), Ok(()));
+        }
+        sector.retain_range(5..11, |_| true);
+    }
+
+    #[test]
+    fn test_contains_and_binary_search() {
+        let sector: Sector<Manual, i32> = Sector::from([1, 3, 5, 7]);
+
+        assert!(sector.contains(&5));
+        assert!(!sector.contains(&4));
+        assert_eq!(sector.binary_search(&7), Ok(3));
+        assert_eq!(sector.binary_search(&4), Err(2));
+        assert_eq!(sector.binary_search(&0), Err(0));
+    }
+
+    #[test]
+    fn test_into_iter_by_reference() {
+        let mut sector: Sector<Manual, i32> = Sector::from([1, 2, 3]);
+
+        for elem in &mut sector {
+            *elem *= 10;
+        }
+
+        let mut sum = 0;
+        for elem in &sector {
+            sum += elem;
+        }
+        assert_eq!(sum, 60);
+    }
+
+    #[test]
+    fn test_reserve_exact() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(2);
+        assert_eq!(sector.push(1), Ok(()));
+
+        assert!(sector.reserve_exact(10));
+        assert_eq!(sector.capacity(), 10);
+        assert_eq!(&*sector, &[1]);
+
+        // Already large enough
+        assert!(sector.reserve_exact(4));
+        assert_eq!(sector.capacity(), 10);
+    }
+
+    #[test]
+    fn test_reserve_exact_failure() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(2);
+
+        assert!(!sector.reserve_exact(usize::MAX));
+        assert_eq!(sector.capacity(), 2);
+    }
+
+    #[test]
+    fn test_reserve_exact_zst() {
+        let mut sector: Sector<Manual, ZeroSizedType> = Sector::with_capacity(1);
+        assert_eq!(sector.push(ZeroSizedType), Ok(()));
+        assert_eq!(sector.push(ZeroSizedType), Err(ZeroSizedType));
+
+        assert!(sector.reserve_exact(2));
+        assert_eq!(sector.push(ZeroSizedType), Ok(()));
+        assert_eq!(sector.push(ZeroSizedType), Err(ZeroSizedType));
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut sector: Sector<Manual, i32> = Sector::with_capacity(10);
+        repeat!(assert_eq!(sector.push(1), Ok(())), 6);
+        repeat!(sector.pop(), 2);
+
+        assert_eq!(sector.shrink_to_fit(), 6);
+        assert_eq!(sector.capacity(), 4);
+        assert_eq!(&*sector, &[1; 4]);
+
+        assert_eq!(sector.shrink_to_fit(), 0);
+        assert_eq!(sector.capacity(), 4);
+    }
+
+    #[test]
+    fn test_shrink_to_fit_zst() {
+        let mut sector: Sector<Manual, ZeroSizedType> = Sector::with_capacity(3);
+        assert_eq!(sector.push(ZeroSizedType), Ok(()));
+
+        assert_eq!(sector.shrink_to_fit(), 0);
+        assert_eq!(sector.len(), 1);
+    }
+
+    #[test]
+    fn test_take() {
+        let mut sector: Sector<Manual, i32> = Sector::from([1, 2, 3]);
+        let ptr = unsafe { sector.as_ptr() };
+
+        let taken = sector.take();
+
+        assert_eq!(&*taken, &[1, 2, 3]);
+        assert_eq!(unsafe { taken.as_ptr() }, ptr);
+        assert!(sector.is_empty());
+        assert_eq!(sector.capacity(), 0);
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut sector: Sector<Manual, i32> = Sector::from([1, 2, 3]);
+        let cap = sector.capacity();
+
+        sector.fill(7);
+        assert_eq!(&*sector, &[7, 7, 7]);
+
+        let mut next = 0;
+        sector.fill_with(|| {
+            next += 1;
+            next
+        });
+        assert_eq!(&*sector, &[1, 2, 3]);
+        assert_eq!(sector.capacity(), cap);
+
+        let mut empty: Sector<Manual, i32> = Sector::new();
+        empty.fill(1);
+        empty.fill_with(|| unreachable!());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut sector: Sector<Manual, i32> = Sector::from([1, 2, 3, 4, 5]);
+
+        sector.rotate_left(2);
+        assert_eq!(&*sector, &[3, 4, 5, 1, 2]);
+        sector.rotate_right(3);
+        assert_eq!(&*sector, &[5, 1, 2, 3, 4]);
+        sector.rotate_left(5);
+        assert_eq!(&*sector, &[5, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_out_of_bounds() {
+        let mut sector: Sector<Manual, i32> = Sector::from([1, 2, 3, 4, 5]);
+        sector.rotate_right(6);
+    }
+
+    #[test]
+    fn test_partition_point() {
+        let sector: Sector<Manual, i32> = Sector::from([1, 2, 3, 7, 8]);
+        assert_eq!(sector.partition_point(|x| *x < 5), 3);
+        assert_eq!(sector.partition_point(|x| *x < 0), 0);
+        assert_eq!(sector.partition_point(|x| *x < 10), 5);
+    }
 }
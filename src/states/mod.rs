@@ -12,3 +12,8 @@ pub use locked::Locked;
 pub use manual::Manual;
 pub use normal::Normal;
 pub use tight::Tight;
+
+/// Gives every state a human readable name, e.g. for `Debug` output.
+pub trait StateName {
+    const NAME: &'static str;
+}
@@ -15,9 +15,18 @@
 //!   grows, its capacity remains until further growth is needed.
 //!
 //! All other operations behave similarly to those in a standard vector.
-use core::ptr::NonNull;
-
-use crate::components::{Cap, Grow, Index, Insert, Len, Pop, Ptr, Push, Remove, Shrink};
+use core::{
+    cmp,
+    mem::MaybeUninit,
+    ops::RangeBounds,
+    ptr::NonNull,
+    slice::{self, IterMut, SliceIndex},
+};
+
+use crate::components::{
+    Cap, Clear, Dedup, ExtendFrom, Grow, Index, Insert, Len, Pop, Ptr, Push, Remove, Reserve,
+    Retain, Shrink, ShrinkTo, Truncate,
+};
 
 use crate::Sector;
 
@@ -26,6 +35,10 @@ pub struct Normal;
 impl crate::components::DefaultIter for Normal {}
 
 impl crate::components::DefaultDrain for Normal {}
+
+impl super::StateName for Normal {
+    const NAME: &'static str = "Normal";
+}
 /// Acts as the normal Vector from std
 impl<T> Sector<Normal, T> {
     /// Appends an element to the end of the sector.
@@ -63,14 +76,455 @@ impl<T> Sector<Normal, T> {
         self.__remove(index)
     }
 
-    /// Returns a reference to the element at the given index if it exists.
-    pub fn get(&self, index: usize) -> Option<&T> {
-        self.__get(index)
+    /// Returns a reference to an element or subslice depending on the type of index.
+    ///
+    /// - If given a position, returns a reference to the element at that position or `None` if
+    ///   out of bounds.
+    /// - If given a range, returns the subslice corresponding to that range, or `None` if out of
+    ///   bounds.
+    pub fn get<I: SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+        (**self).get(index)
+    }
+
+    /// Returns a mutable reference to an element or subslice depending on the type of index
+    /// (see [`get`](Self::get)) or `None` if the index is out of bounds.
+    pub fn get_mut<I: SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+        (**self).get_mut(index)
+    }
+
+    /// Shortens the sector, keeping the first `new_len` elements and dropping the rest.
+    ///
+    /// Has no effect if `new_len` is greater or equal to the current length.
+    pub fn truncate(&mut self, new_len: usize) {
+        self.__truncate(new_len);
+    }
+
+    /// Keeps the first `n` elements and drops the rest.
+    ///
+    /// This is an alias for [`truncate`](Self::truncate).
+    pub fn keep_first(&mut self, n: usize) {
+        self.__truncate(n);
+    }
+
+    /// Keeps the last `n` elements and drops the rest, shifting the kept elements to the front.
+    ///
+    /// Has no effect if `n` is greater or equal to the current length.
+    pub fn keep_last(&mut self, n: usize) {
+        self.__truncate_front(n);
+    }
+
+    /// Removes the element at the specified index and returns it, replacing it with the last element.
+    ///
+    /// Unlike [`remove`](Self::remove) this does not shift any elements and therefore runs in
+    /// _O(1)_, but it does not preserve the ordering of the remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.__swap_remove(index)
+    }
+
+    /// Removes all but the first of consecutive elements that resolve to the same key.
+    ///
+    /// `key` gets called exactly once per element.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, key: F) {
+        self.__dedup_by_key(key);
+    }
+
+    /// Clones the elements in `src` and appends them to the end of the sector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` is out of bounds or its start is greater than its end.
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R)
+    where
+        T: Clone,
+    {
+        self.__extend_from_within(src);
+    }
+
+    /// Returns a mutable reference to the element at `index`, resizing the sector first if
+    /// `index` is out of bounds.
+    ///
+    /// Any gap up to and including `index` gets filled with clones of `default`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required capacity overflows.
+    pub fn get_mut_or_resize(&mut self, index: usize, default: T) -> &mut T
+    where
+        T: Clone,
+    {
+        let len = self.__len();
+        if index >= len {
+            let required = index.checked_add(1).expect("Capacity overflow");
+            self.__reserve(required - len);
+            while self.__len() <= index {
+                self.__push(default.clone());
+            }
+        }
+        &mut self[index]
+    }
+
+    /// Drops all elements, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.__clear();
+    }
+
+    /// Removes consecutive repeated elements.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.__dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive repeated elements, like [`dedup`](Self::dedup), but faster for `Copy`
+    /// types.
+    pub fn dedup_copy(&mut self)
+    where
+        T: Copy + PartialEq,
+    {
+        self.__dedup_copy();
+    }
+
+    /// Copies all elements of `other` to the end of the sector, growing at most once.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Copy,
+    {
+        self.__extend_from_slice(other);
+    }
+
+    /// Drops all elements so the sector can be reused, e.g. from an object pool.
+    ///
+    /// Unlike dropping and recreating the sector, the capacity is guaranteed to be retained, so
+    /// refilling it up to the previous length does not reallocate.
+    pub fn reset(&mut self) {
+        let capacity = self.__cap();
+        self.__clear();
+        debug_assert_eq!(self.__cap(), capacity, "reset must retain the capacity");
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving their order.
+    ///
+    /// Rejected elements are dropped right away, in a single pass over the sector.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.__retain_mut(|elem| f(elem));
+    }
+
+    /// Keeps only the elements within `range` for which `f` returns `true`, leaving the elements
+    /// outside of `range` untouched.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds or its start is greater than its end.
+    pub fn retain_range<R: RangeBounds<usize>, F: FnMut(&T) -> bool>(
+        &mut self,
+        range: R,
+        mut f: F,
+    ) {
+        self.__retain_range_mut(range, |elem| f(elem));
+    }
+
+    /// Returns an iterator over mutable references to all elements.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        (**self).iter_mut()
+    }
+
+    /// Splits the sector in two at `at`.
+    ///
+    /// `self` keeps the elements `0..at`, the elements `at..len` are moved into the returned
+    /// sector, which is allocated with exactly enough capacity for them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length.
+    pub fn split_off(&mut self, at: usize) -> Sector<Normal, T> {
+        let len = self.__len();
+        let other = self.split_off_into(at);
+        unsafe { self.__shrink(len, at) };
+        other
+    }
+
+    /// Moves all elements of `other` to the end of the sector, leaving `other` empty.
+    ///
+    /// No element is dropped or cloned, and the capacity of `other` stays allocated.
+    pub fn append<OtherState>(&mut self, other: &mut Sector<OtherState, T>) {
+        self.__reserve(other.len());
+        unsafe { self.move_from_unchecked(other) };
+    }
+
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// The capacity may grow beyond that, following the growth policy of the state. Does nothing
+    /// if the capacity already suffices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        self.__reserve(additional);
+    }
+
+    /// Reserves capacity for exactly `additional` more elements.
+    ///
+    /// Does nothing if the capacity already suffices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.__reserve_exact(additional);
+    }
+
+    /// Shrinks the capacity to exactly the length, freeing the allocation if the sector is empty.
+    ///
+    /// Does nothing for zero-sized types or if there is no spare capacity.
+    pub fn shrink_to_fit(&mut self) {
+        self.__shrink_to_fit();
+    }
+
+    /// Shortens the sector to `new_len` elements, dropping the rest.
+    ///
+    /// Unlike a resize this can never grow the sector: if `new_len` is greater or equal to the
+    /// current length, nothing happens.
+    pub fn shorten_to(&mut self, new_len: usize) {
+        self.__truncate(new_len);
+    }
+
+    /// Shrinks the capacity to `max(len, min_capacity)`, keeping some headroom for future
+    /// elements.
+    ///
+    /// Does nothing if `min_capacity` is greater or equal to the current capacity.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.__shrink_to(min_capacity);
+    }
+
+    /// Reclaims unused memory by shrinking the capacity to exactly the length.
+    ///
+    /// Same as [`shrink_to_fit`](Self::shrink_to_fit), available on every state.
+    pub fn compact(&mut self) {
+        self.__shrink_to_fit();
+    }
+
+    /// Moves all elements of every sector in `iter` to the end of this one, in order.
+    ///
+    /// Room for each source is reserved before its elements are moved over; nothing is cloned.
+    pub fn extend_flatten<S2, I: IntoIterator<Item = Sector<S2, T>>>(&mut self, iter: I) {
+        for mut other in iter {
+            self.__reserve(other.len());
+            unsafe { self.move_from_unchecked(&mut other) };
+        }
+    }
+
+    /// Returns the elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    /// Returns the elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    /// Returns `true` if the sector contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        (**self).contains(x)
+    }
+
+    /// Binary searches the sorted sector for `x`.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index where `x` could
+    /// be inserted while keeping the order. See [`slice::binary_search`] for details.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        (**self).binary_search(x)
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`.
+    ///
+    /// The sector has to be partitioned by `pred`, i.e. all elements it accepts come first. See
+    /// [`slice::partition_point`] for details.
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, pred: F) -> usize {
+        (**self).partition_point(pred)
+    }
+
+    /// Returns a mutable reference to the last element, pushing `f()` first if the sector is
+    /// empty.
+    ///
+    /// `f` only gets called if the sector is empty.
+    pub fn get_or_push_last<F: FnOnce() -> T>(&mut self, f: F) -> &mut T {
+        if self.__len() == 0 {
+            self.__push(f());
+        }
+        let last = self.__len() - 1;
+        &mut self[last]
+    }
+
+    /// Clones all elements of `items` to the front of the sector, keeping their order.
+    ///
+    /// The sector grows at most once and the existing elements are only shifted once.
+    pub fn prepend_from_slice(&mut self, items: &[T])
+    where
+        T: Clone,
+    {
+        self.__prepend_from_slice(items);
+    }
+
+    /// Drops elements from the back as long as `pred` returns `true` for the last one.
+    ///
+    /// The matching elements are removed at once, the capacity stays unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The number of removed elements.
+    pub fn pop_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+        let count = self.iter().rev().take_while(|elem| pred(elem)).count();
+        self.__truncate(self.__len() - count);
+        count
+    }
+
+    /// Reserves room for `additional` elements and lets `fill` initialize the front of it,
+    /// e.g. by reading from a socket.
+    ///
+    /// `fill` gets the uninitialized spare room and returns how many elements it initialized,
+    /// the length grows by exactly that count.
+    ///
+    /// # Safety
+    ///
+    /// `fill` gets called exactly once. The caller must guarantee that the count `n` it returns
+    /// is at most the length of the slice it was given (`additional`), and that the first `n`
+    /// elements of that slice are initialized when it returns. Elements after the first `n` may
+    /// stay uninitialized, they are not part of the sector.
+    ///
+    /// # Panics
+    ///
+    /// If `fill` returns a count greater than `additional`. This is checked before the length
+    /// changes.
+    pub unsafe fn read_into_tail<F: FnOnce(&mut [MaybeUninit<T>]) -> usize>(
+        &mut self,
+        additional: usize,
+        fill: F,
+    ) {
+        self.__reserve(additional);
+
+        let len = self.__len();
+        let tail = slice::from_raw_parts_mut(
+            self.__ptr().as_ptr().add(len) as *mut MaybeUninit<T>,
+            additional,
+        );
+        let filled = fill(tail);
+        assert!(
+            filled <= additional,
+            "filled {filled} elements but only {additional} were reserved"
+        );
+        self.__len_set(len + filled);
+    }
+
+    /// Removes all but the first of consecutive elements that `eq` considers equal, assuming
+    /// the sector is sorted by whatever `eq` compares, e.g. a key.
+    ///
+    /// Sorted input keeps all equal elements next to each other, so a single O(n) pass removes
+    /// every duplicate. `eq` gets passed the kept element first. The order is not checked, on
+    /// unsorted input only consecutive duplicates get removed.
+    pub fn dedup_by_sorted<F: FnMut(&T, &T) -> bool>(&mut self, mut eq: F) {
+        self.__dedup_by(|current, kept| eq(kept, current));
+    }
+
+    /// Reserves capacity for at least `additional` more elements, ending up with the same capacity
+    /// as pushing them one by one would.
+    ///
+    /// Pushing into a full sector doubles its capacity, so this keeps doubling until
+    /// `len + additional` fits and then grows once. [`reserve`](Self::reserve) adds the current
+    /// capacity as often as needed instead, so it may end up with less, e.g. 24 instead of 32 when
+    /// reserving 20 more elements in a full sector of 4. Does nothing if the capacity already
+    /// suffices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows.
+    pub fn reserve_for_push(&mut self, additional: usize) {
+        let len = self.__len();
+        let required = len.checked_add(additional).expect("Capacity overflow");
+        if required <= self.__cap() || size_of::<T>() == 0 {
+            return;
+        }
+
+        let mut target = cmp::max(self.__cap(), 1);
+        while target < required {
+            target = target.saturating_mul(2);
+        }
+        self.__reserve_exact(target - len);
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving their order.
+    ///
+    /// Like [`retain`](Self::retain), but `f` may also modify the elements it inspects.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        self.__retain_mut(f);
+    }
+
+    /// Caps the sector at `max_len` elements, dropping the excess tail.
+    ///
+    /// Does nothing if the sector already holds at most `max_len` elements.
+    pub fn clamp_len(&mut self, max_len: usize) {
+        self.__truncate(max_len);
+    }
+
+    /// Overwrites every element with clones of `value`, dropping the old elements.
+    ///
+    /// Length and capacity stay the same.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        (**self).fill(value);
+    }
+
+    /// Overwrites every element with the values returned by `f`, dropping the old elements.
+    ///
+    /// Length and capacity stay the same.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        (**self).fill_with(f);
+    }
+
+    /// Rotates the elements in place so the element at `mid` becomes the first one.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `mid` is greater than the length.
+    pub fn rotate_left(&mut self, mid: usize) {
+        (**self).rotate_left(mid);
+    }
+
+    /// Rotates the elements in place so the last `k` elements move to the front.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `k` is greater than the length.
+    pub fn rotate_right(&mut self, k: usize) {
+        (**self).rotate_right(k);
+    }
+
+    /// Reverses the order of the elements in place.
+    pub fn reverse(&mut self) {
+        (**self).reverse();
     }
+}
 
-    /// Returns a mutable reference to the element at the given index if it exists.
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        self.__get_mut(index)
+impl<'a, T> IntoIterator for &'a mut Sector<Normal, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
@@ -160,6 +614,13 @@ impl<T> Pop<T> for Sector<Normal, T> {}
 impl<T> Insert<T> for Sector<Normal, T> {}
 impl<T> Index<T> for Sector<Normal, T> {}
 impl<T> Remove<T> for Sector<Normal, T> {}
+impl<T> Truncate<T> for Sector<Normal, T> {}
+impl<T> Dedup<T> for Sector<Normal, T> {}
+impl<T> Reserve<T> for Sector<Normal, T> {}
+impl<T> ExtendFrom<T> for Sector<Normal, T> {}
+impl<T> Clear<T> for Sector<Normal, T> {}
+impl<T> Retain<T> for Sector<Normal, T> {}
+impl<T> ShrinkTo<T> for Sector<Normal, T> {}
 
 #[cfg(test)]
 mod tests {
@@ -718,4 +1179,1098 @@ mod tests {
         repeat!(sector.pop(), 1000);
         assert_eq!(sector.capacity(), 1024);
     }
+
+    #[test]
+    fn test_recycle() {
+        let mut sector: Sector<Normal, u32> = Sector::new();
+        repeat!(sector.push(0xAABBCCDD), 10);
+
+        let ptr = unsafe { sector.as_ptr() }.as_ptr() as usize;
+        let capacity = sector.capacity();
+
+        let mut recycled: Sector<Normal, f32> = sector.recycle();
+
+        assert_eq!(recycled.len(), 0);
+        assert_eq!(recycled.capacity(), capacity);
+        assert_eq!(unsafe { recycled.as_ptr() }.as_ptr() as usize, ptr);
+
+        recycled.push(1.5);
+        assert_eq!(recycled.get(0), Some(&1.5));
+    }
+
+    #[test]
+    fn test_recycle_different_align() {
+        let mut sector: Sector<Normal, u32> = Sector::new();
+        repeat!(sector.push(0xAABBCCDD), 10);
+        let capacity = sector.capacity();
+
+        // `[u8; 4]` has the same size as `u32` but a smaller alignment
+        let mut recycled: Sector<Normal, [u8; 4]> = sector.recycle();
+
+        assert_eq!(recycled.len(), 0);
+        assert_eq!(recycled.capacity(), capacity);
+
+        recycled.push([1, 2, 3, 4]);
+        assert_eq!(recycled.get(0), Some(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_map_into_same_layout() {
+        let mut sector: Sector<Normal, u32> = Sector::with_capacity(8);
+        repeat!(sector.push(7), 5);
+        let ptr = unsafe { sector.as_ptr() }.as_ptr() as usize;
+
+        let mapped: Sector<Normal, i32> = sector.map_into(|x| -(x as i32));
+
+        // The allocation is reused
+        assert_eq!(unsafe { mapped.as_ptr() }.as_ptr() as usize, ptr);
+        assert_eq!(mapped.capacity(), 8);
+        assert_eq!(&*mapped, &[-7; 5]);
+    }
+
+    #[test]
+    fn test_map_into_different_layout() {
+        let mut sector: Sector<Normal, u32> = Sector::with_capacity(8);
+        repeat!(sector.push(u32::MAX), 3);
+
+        let mapped: Sector<Normal, u64> = sector.map_into(|x| x as u64 + 1);
+
+        assert_eq!(mapped.capacity(), 3);
+        assert_eq!(&*mapped, &[1 << 32; 3]);
+    }
+
+    #[test]
+    fn test_recycle_incompatible() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Normal, DropCounter> = Sector::new();
+        repeat!(sector.push(DropCounter { counter: &counter }), 5);
+
+        let recycled: Sector<Normal, u8> = sector.recycle();
+
+        assert_eq!(counter.get(), 5);
+        assert_eq!(recycled.len(), 0);
+    }
+
+    #[test]
+    fn test_get_range() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+
+        sector.push(10);
+        sector.push(20);
+        sector.push(30);
+
+        assert_eq!(sector.get(1..3), Some(&[20, 30][..]));
+        assert_eq!(sector.get(..), Some(&[10, 20, 30][..]));
+        assert_eq!(sector.get(2..4), None);
+        assert_eq!(sector.get(3), None);
+
+        if let Some(slice) = sector.get_mut(..2) {
+            slice[1] = 25;
+        }
+        assert_eq!(sector.get(1), Some(&25));
+        assert_eq!(sector.get_mut(2..=3), None);
+    }
+
+    #[test]
+    fn test_keep_first() {
+        let mut sector: Sector<Normal, char> = Sector::new();
+        sector.push('a');
+        sector.push('b');
+        sector.push('c');
+        sector.push('d');
+
+        sector.keep_first(2);
+        assert_eq!(&*sector, &['a', 'b']);
+
+        sector.keep_first(5);
+        assert_eq!(&*sector, &['a', 'b']);
+    }
+
+    #[test]
+    fn test_keep_last() {
+        let mut sector: Sector<Normal, char> = Sector::new();
+        sector.push('a');
+        sector.push('b');
+        sector.push('c');
+        sector.push('d');
+
+        sector.keep_last(2);
+        assert_eq!(&*sector, &['c', 'd']);
+
+        sector.keep_last(5);
+        assert_eq!(&*sector, &['c', 'd']);
+
+        sector.keep_last(0);
+        assert_eq!(sector.len(), 0);
+    }
+
+    #[test]
+    fn test_truncate_drop() {
+        let counter = core::cell::Cell::new(0);
+        {
+            let mut sector: Sector<Normal, DropCounter> = Sector::new();
+            repeat!(sector.push(DropCounter { counter: &counter }), 6);
+
+            sector.truncate(4);
+            assert_eq!(counter.get(), 2);
+
+            sector.keep_last(1);
+            assert_eq!(counter.get(), 5);
+            assert_eq!(sector.len(), 1);
+        }
+        assert_eq!(counter.get(), 6);
+    }
+
+    #[test]
+    fn test_swap_remove() {
+        let mut removed: Sector<Normal, char> = Sector::new();
+        let mut swap_removed: Sector<Normal, char> = Sector::new();
+        for c in ['a', 'b', 'c', 'd'] {
+            removed.push(c);
+            swap_removed.push(c);
+        }
+
+        assert_eq!(removed.remove(1), 'b');
+        assert_eq!(swap_removed.swap_remove(1), 'b');
+
+        assert_eq!(&*removed, &['a', 'c', 'd']);
+        assert_eq!(&*swap_removed, &['a', 'd', 'c']);
+
+        assert_eq!(swap_removed.swap_remove(2), 'c');
+        assert_eq!(&*swap_removed, &['a', 'd']);
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_remove index (is 1) should be < len (is 1)")]
+    fn test_swap_remove_out_of_bounds() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        sector.push(1);
+
+        sector.swap_remove(1);
+    }
+
+    #[test]
+    fn test_dedup_by_key() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        for i in [10, 11, 20, 21, 22, 30, 10, 12] {
+            sector.push(i);
+        }
+
+        sector.dedup_by_key(|x| *x / 10);
+        assert_eq!(&*sector, &[10, 20, 30, 10]);
+    }
+
+    #[test]
+    fn test_dedup_by_key_calls_key_once() {
+        let calls = core::cell::Cell::new(0);
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        repeat!(sector.push(1), 1000);
+        sector.push(2);
+
+        sector.dedup_by_key(|x| {
+            calls.set(calls.get() + 1);
+            *x
+        });
+
+        assert_eq!(&*sector, &[1, 2]);
+        assert_eq!(calls.get(), 1001);
+    }
+
+    #[test]
+    fn test_drop_elements() {
+        let counter = core::cell::Cell::new(0);
+        {
+            let mut sector: Sector<Normal, DropCounter> = Sector::new();
+            repeat!(sector.push(DropCounter { counter: &counter }), 100);
+        }
+        assert_eq!(counter.get(), 100);
+    }
+
+    #[test]
+    fn test_drop_trivial_elements() {
+        // `Drop` skips visiting the elements for types without drop glue
+        assert!(!core::mem::needs_drop::<i32>());
+
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        repeat!(sector.push(7), 10_000);
+        drop(sector);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 5) should be <= len (is 3)")]
+    fn test_insert_past_end() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        repeat!(sector.push(1), 3);
+
+        sector.insert(5, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index (is 3) should be < len (is 3)")]
+    fn test_remove_out_of_bounds() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        repeat!(sector.push(1), 3);
+
+        sector.remove(3);
+    }
+
+    #[test]
+    fn test_push_many_zst() {
+        let mut sector: Sector<Normal, ZeroSizedType> = Sector::new();
+        let ptr = sector.__ptr();
+        assert_eq!(sector.capacity(), usize::MAX);
+
+        repeat!(sector.push(ZeroSizedType), 10_000);
+        assert_eq!(sector.len(), 10_000);
+        assert_eq!(sector.capacity(), usize::MAX);
+        assert_eq!(sector.__ptr(), ptr);
+
+        repeat!(sector.pop(), 9_999);
+        assert_eq!(sector.len(), 1);
+        assert_eq!(sector.capacity(), usize::MAX);
+    }
+
+    #[test]
+    fn test_extend_from_within() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        for i in 0..4 {
+            sector.push(i);
+        }
+
+        sector.extend_from_within(1..3);
+        assert_eq!(&*sector, &[0, 1, 2, 3, 1, 2]);
+    }
+
+    #[test]
+    fn test_extend_from_within_reallocating() {
+        let mut sector: Sector<Normal, String> = Sector::new();
+        for s in ["a", "b", "c", "d"] {
+            sector.push(s.into());
+        }
+        let len = sector.len();
+        assert_eq!(sector.capacity(), len);
+
+        sector.extend_from_within(0..len);
+        sector.extend_from_within(..);
+        assert_eq!(
+            &*sector,
+            &["a", "b", "c", "d", "a", "b", "c", "d", "a", "b", "c", "d", "a", "b", "c", "d"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 5 out of range for slice of length 4")]
+    fn test_extend_from_within_out_of_bounds() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        repeat!(sector.push(1), 4);
+
+        sector.extend_from_within(2..5);
+    }
+
+    #[test]
+    fn test_get_mut_or_resize() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+
+        *sector.get_mut_or_resize(4, 0) = 7;
+        assert_eq!(&*sector, &[0, 0, 0, 0, 7]);
+
+        *sector.get_mut_or_resize(1, -1) += 2;
+        assert_eq!(&*sector, &[0, 2, 0, 0, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Capacity overflow")]
+    fn test_get_mut_or_resize_max_index() {
+        let mut sector: Sector<Normal, i32> = Sector::from([1]);
+        sector.get_mut_or_resize(usize::MAX, 0);
+    }
+
+    #[test]
+    fn test_insert_front_grows_amortized() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        let mut reallocations = 0;
+        for i in 0..1000 {
+            let cap = sector.capacity();
+            sector.insert(0, i);
+            if sector.capacity() != cap {
+                reallocations += 1;
+            }
+        }
+
+        assert_eq!(sector.len(), 1000);
+        assert_eq!(sector[0], 999);
+        assert_eq!(sector[999], 0);
+        // Capacity doubles every time: 1, 2, 4, ..., 1024
+        assert_eq!(reallocations, 11);
+    }
+
+    #[test]
+    fn test_clear() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Normal, DropCounter> = Sector::with_capacity(8);
+        repeat!(sector.push(DropCounter { counter: &counter }), 8);
+        let cap = sector.capacity();
+
+        sector.clear();
+        assert_eq!(counter.get(), 8);
+        assert_eq!(sector.len(), 0);
+        assert_eq!(sector.capacity(), cap);
+    }
+
+    #[test]
+    fn test_clear_zst() {
+        let mut sector: Sector<Normal, ZeroSizedType> = Sector::new();
+        repeat!(sector.push(ZeroSizedType), 5);
+
+        sector.clear();
+        assert_eq!(sector.len(), 0);
+        assert_eq!(sector.capacity(), usize::MAX);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut sector: Sector<Normal, String> = Sector::new();
+        for s in ["a", "a", "b", "a", "c", "c", "c"] {
+            sector.push(s.into());
+        }
+
+        sector.dedup();
+        assert_eq!(&*sector, &["a", "b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_dedup_copy_matches_dedup() {
+        let mut generic: Sector<Normal, u32> = Sector::new();
+        let mut copy: Sector<Normal, u32> = Sector::new();
+        for i in 0..10_000u32 {
+            let value = i / 3 % 7;
+            generic.push(value);
+            copy.push(value);
+        }
+
+        generic.dedup();
+        copy.dedup_copy();
+        assert_eq!(&*copy, &*generic);
+        assert_eq!(copy.len(), 3334);
+    }
+
+    #[test]
+    fn test_extend_from_slice() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        sector.push(0);
+
+        sector.extend_from_slice(&[1, 2, 3, 4]);
+        sector.extend_from_slice(&[]);
+        assert_eq!(&*sector, &[0, 1, 2, 3, 4]);
+        assert!(sector.capacity() >= 5);
+    }
+
+    #[test]
+    fn test_reset_pool_cycle() {
+        let mut pooled: Sector<Normal, i32> = Sector::with_capacity(16);
+        let ptr = pooled.__ptr();
+
+        for cycle in 0..3 {
+            for i in 0..16 {
+                pooled.push(cycle * 16 + i);
+            }
+            assert_eq!(pooled.len(), 16);
+            assert_eq!(pooled[0], cycle * 16);
+
+            pooled.reset();
+            assert!(pooled.is_empty());
+            assert_eq!(pooled.capacity(), 16);
+            assert_eq!(pooled.__ptr(), ptr);
+        }
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        for i in 0..10 {
+            sector.push(i);
+        }
+
+        sector.retain(|x| x % 2 == 1);
+        assert_eq!(&*sector, &[1, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut sector: Sector<Normal, i32> = Sector::with_capacity(3);
+        for i in 1..=3 {
+            sector.push(i);
+        }
+
+        for elem in sector.iter_mut() {
+            *elem *= 10;
+        }
+        assert_eq!(&*sector, &[10, 20, 30]);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        for i in 0..5 {
+            sector.push(i);
+        }
+
+        let other = sector.split_off(2);
+        assert_eq!(&*sector, &[0, 1]);
+        assert_eq!(&*other, &[2, 3, 4]);
+        assert_eq!(other.capacity(), 3);
+
+        let mut sector = other;
+        let empty = sector.split_off(3);
+        assert!(empty.is_empty());
+        assert_eq!(sector.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "`at` split index (is 3) should be <= len (is 2)")]
+    fn test_split_off_out_of_bounds() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        repeat!(sector.push(1), 2);
+
+        sector.split_off(3);
+    }
+
+    #[test]
+    fn test_split_off_zst() {
+        let mut sector: Sector<Normal, ZeroSizedType> = Sector::new();
+        repeat!(sector.push(ZeroSizedType), 5);
+
+        let other = sector.split_off(1);
+        assert_eq!(sector.len(), 1);
+        assert_eq!(other.len(), 4);
+    }
+
+    #[test]
+    fn test_split_off_does_not_drop() {
+        let counter = core::cell::Cell::new(0);
+        {
+            let mut sector: Sector<Normal, DropCounter> = Sector::new();
+            repeat!(sector.push(DropCounter { counter: &counter }), 6);
+
+            let other = sector.split_off(2);
+            assert_eq!(counter.get(), 0);
+            assert_eq!(other.len(), 4);
+        }
+        assert_eq!(counter.get(), 6);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        sector.push(0);
+
+        let mut fixed: Sector<crate::states::Fixed, i32> = Sector::with_capacity(4);
+        for i in 1..=3 {
+            assert_eq!(fixed.push(i), Ok(()));
+        }
+
+        sector.append(&mut fixed);
+        assert_eq!(&*sector, &[0, 1, 2, 3]);
+        assert!(fixed.is_empty());
+        assert_eq!(fixed.capacity(), 4);
+    }
+
+    #[test]
+    fn test_append_does_not_drop() {
+        let counter = core::cell::Cell::new(0);
+        {
+            let mut sector: Sector<Normal, DropCounter> = Sector::new();
+            let mut other: Sector<Normal, DropCounter> = Sector::new();
+            repeat!(other.push(DropCounter { counter: &counter }), 5);
+
+            sector.append(&mut other);
+            assert_eq!(counter.get(), 0);
+            assert_eq!(sector.len(), 5);
+        }
+        assert_eq!(counter.get(), 5);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        repeat!(sector.push(1), 4);
+
+        sector.reserve(1);
+        assert_eq!(sector.capacity(), 8);
+
+        sector.reserve(2);
+        assert_eq!(sector.capacity(), 8);
+
+        sector.reserve(20);
+        assert_eq!(sector.capacity(), 24);
+    }
+
+    #[test]
+    fn test_reserve_exact() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        repeat!(sector.push(1), 4);
+
+        sector.reserve_exact(3);
+        assert_eq!(sector.capacity(), 7);
+
+        sector.reserve_exact(2);
+        assert_eq!(sector.capacity(), 7);
+        assert_eq!(sector.len(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Capacity overflow")]
+    fn test_reserve_overflow() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        sector.push(1);
+
+        sector.reserve(usize::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reserve_exact_layout_overflow() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+
+        sector.reserve_exact(usize::MAX / 2);
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        for i in 0..1000 {
+            sector.push(i);
+        }
+        repeat!(sector.pop(), 990);
+
+        sector.shrink_to_fit();
+        assert_eq!(sector.capacity(), 10);
+        assert_eq!(&*sector, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        sector.shrink_to_fit();
+        assert_eq!(sector.capacity(), 10);
+
+        sector.clear();
+        sector.shrink_to_fit();
+        assert_eq!(sector.capacity(), 0);
+    }
+
+    #[test]
+    fn test_shrink_to_fit_zst() {
+        let mut sector: Sector<Normal, ZeroSizedType> = Sector::new();
+        repeat!(sector.push(ZeroSizedType), 5);
+
+        sector.shrink_to_fit();
+        assert_eq!(sector.capacity(), usize::MAX);
+        assert_eq!(sector.len(), 5);
+    }
+
+    #[test]
+    fn test_shorten_to() {
+        let mut sector: Sector<Normal, i32> = Sector::with_capacity(5);
+        for i in 0..5 {
+            sector.push(i);
+        }
+
+        sector.shorten_to(7);
+        assert_eq!(sector.len(), 5);
+        sector.shorten_to(5);
+        assert_eq!(sector.len(), 5);
+
+        sector.shorten_to(2);
+        assert_eq!(&*sector, &[0, 1]);
+    }
+
+    #[test]
+    fn test_shrink_to() {
+        let mut sector: Sector<Normal, i32> = Sector::with_capacity(100);
+        for i in 0..10 {
+            sector.push(i);
+        }
+
+        sector.shrink_to(200);
+        assert_eq!(sector.capacity(), 100);
+
+        sector.shrink_to(50);
+        assert_eq!(sector.capacity(), 50);
+
+        sector.shrink_to(2);
+        assert_eq!(sector.capacity(), 10);
+        assert_eq!(sector.len(), 10);
+    }
+
+    #[test]
+    fn test_shrink_to_empty_deallocates() {
+        let mut sector: Sector<Normal, i32> = Sector::with_capacity(8);
+
+        sector.shrink_to(0);
+        assert_eq!(sector.capacity(), 0);
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut sector: Sector<Normal, i32> = Sector::with_capacity(16);
+        for i in 0..4 {
+            sector.push(i);
+        }
+
+        sector.compact();
+        assert_eq!(sector.capacity(), 4);
+        assert_eq!(&*sector, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_extend_flatten() {
+        let counter = core::cell::Cell::new(0);
+        {
+            let mut sector: Sector<Normal, DropCounter> = Sector::new();
+            sector.push(DropCounter { counter: &counter });
+
+            let mut sources: [Sector<crate::states::Tight, DropCounter>; 3] =
+                [Sector::new(), Sector::new(), Sector::new()];
+            for (i, source) in sources.iter_mut().enumerate() {
+                repeat!(source.push(DropCounter { counter: &counter }), i + 1);
+            }
+
+            sector.extend_flatten(sources);
+            assert_eq!(sector.len(), 7);
+            assert_eq!(counter.get(), 0);
+        }
+        assert_eq!(counter.get(), 7);
+    }
+
+    #[test]
+    fn test_extend_flatten_order() {
+        let mut sector: Sector<Normal, i32> = Sector::from([0]);
+        let sources: [Sector<Normal, i32>; 3] =
+            [Sector::from([1, 2]), Sector::new(), Sector::from([3])];
+
+        sector.extend_flatten(sources);
+        assert_eq!(&*sector, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let mut sector: Sector<Normal, i32> = Sector::from([3, 1, 2]);
+
+        sector.as_mut_slice().sort();
+        assert_eq!(sector.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_retain_range() {
+        let mut sector: Sector<Normal, i32> = (0..10).collect();
+
+        sector.retain_range(2..6, |x| x % 2 == 0);
+        assert_eq!(&*sector, &[0, 1, 2, 4, 6, 7, 8, 9]);
+
+        sector.retain_range(6.., |_| false);
+        assert_eq!(&*sector, &[0, 1, 2, 4, 6, 7]);
+
+        sector.retain_range(..0, |_| false);
+        assert_eq!(sector.len(), 6);
+    }
+
+    #[test]
+    fn test_contains_and_binary_search() {
+        let sector: Sector<Normal, i32> = Sector::from([1, 3, 5, 7]);
+
+        assert!(sector.contains(&5));
+        assert!(!sector.contains(&4));
+        assert_eq!(sector.binary_search(&7), Ok(3));
+        assert_eq!(sector.binary_search(&4), Err(2));
+        assert_eq!(sector.binary_search(&0), Err(0));
+    }
+
+    #[test]
+    fn test_get_or_push_last() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+
+        *sector.get_or_push_last(|| 10) += 1;
+        assert_eq!(&*sector, &[11]);
+
+        sector.push(20);
+        *sector.get_or_push_last(|| unreachable!()) += 1;
+        assert_eq!(&*sector, &[11, 21]);
+    }
+
+    #[test]
+    fn test_swap_sectors() {
+        let mut a: Sector<Normal, i32> = Sector::from([1, 2, 3]);
+        let mut b: Sector<Normal, i32> = Sector::from([4]);
+        let (ptr_a, cap_a) = (unsafe { a.as_ptr() }, a.capacity());
+        let (ptr_b, cap_b) = (unsafe { b.as_ptr() }, b.capacity());
+
+        a.swap_sectors(&mut b);
+
+        assert_eq!(&*a, &[4]);
+        assert_eq!(&*b, &[1, 2, 3]);
+        assert_eq!(unsafe { a.as_ptr() }, ptr_b);
+        assert_eq!(unsafe { b.as_ptr() }, ptr_a);
+        assert_eq!((a.capacity(), b.capacity()), (cap_b, cap_a));
+    }
+
+    #[test]
+    fn test_drain_range() {
+        let mut sector: Sector<Normal, i32> = (0..8).collect();
+        let cap = sector.capacity();
+
+        let drained: Sector<Normal, i32> = sector.drain_range(2..5).collect();
+        assert_eq!(&*drained, &[2, 3, 4]);
+        assert_eq!(&*sector, &[0, 1, 5, 6, 7]);
+        assert_eq!(sector.capacity(), cap);
+
+        sector.drain_range(3..);
+        assert_eq!(&*sector, &[0, 1, 5]);
+
+        sector.drain_range(..=0);
+        assert_eq!(&*sector, &[1, 5]);
+
+        sector.drain_range(1..1);
+        assert_eq!(&*sector, &[1, 5]);
+    }
+
+    #[test]
+    fn test_drain_range_partially_consumed() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Normal, (i32, DropCounter)> = Sector::new();
+        for i in 0..6 {
+            sector.push((i, DropCounter { counter: &counter }));
+        }
+
+        {
+            let mut drain = sector.drain_range(1..5);
+            assert_eq!(drain.next().map(|(i, _)| i), Some(1));
+            assert_eq!(drain.next_back().map(|(i, _)| i), Some(4));
+        }
+
+        assert_eq!(counter.get(), 4);
+        assert_eq!(sector.len(), 2);
+        assert_eq!(sector[0].0, 0);
+        assert_eq!(sector[1].0, 5);
+    }
+
+    #[test]
+    fn test_drain_range_zst() {
+        let mut sector: Sector<Normal, ZeroSizedType> = Sector::new();
+        repeat!(sector.push(ZeroSizedType), 5);
+
+        assert_eq!(sector.drain_range(1..3).count(), 2);
+        assert_eq!(sector.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 3 but ends at 2")]
+    fn test_drain_range_inverted() {
+        let mut sector: Sector<Normal, i32> = (0..4).collect();
+        #[allow(clippy::reversed_empty_ranges)]
+        sector.drain_range(3..2);
+    }
+
+    #[test]
+    fn test_prepend_from_slice() {
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 2, 3]);
+
+        sector.prepend_from_slice(&[-1, 0]);
+        assert_eq!(&*sector, &[-1, 0, 1, 2, 3]);
+
+        sector.prepend_from_slice(&[]);
+        assert_eq!(sector.len(), 5);
+
+        let mut empty: Sector<Normal, i32> = Sector::new();
+        empty.prepend_from_slice(&[7, 8]);
+        assert_eq!(&*empty, &[7, 8]);
+    }
+
+    #[test]
+    fn test_into_iter_by_reference() {
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 2, 3]);
+
+        for elem in &mut sector {
+            *elem *= 10;
+        }
+
+        let mut sum = 0;
+        for elem in &sector {
+            sum += elem;
+        }
+        assert_eq!(sum, 60);
+    }
+
+    #[test]
+    fn test_pop_while() {
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 2, 0, 0, 0]);
+
+        assert_eq!(sector.pop_while(|x| *x == 0), 3);
+        assert_eq!(&*sector, &[1, 2]);
+
+        assert_eq!(sector.pop_while(|x| *x == 0), 0);
+        assert_eq!(sector.pop_while(|_| true), 2);
+        assert!(sector.is_empty());
+    }
+
+    #[test]
+    fn test_drain_windows() {
+        let mut sector: Sector<Normal, i32> = (1..=7).collect();
+
+        let mut chunks = sector.drain_windows(3);
+        assert_eq!(chunks.next().as_deref(), Some(&[1, 2, 3][..]));
+        assert_eq!(chunks.next().as_deref(), Some(&[4, 5, 6][..]));
+        assert!(chunks.next().is_none());
+        drop(chunks);
+
+        assert_eq!(&*sector, &[7]);
+    }
+
+    #[test]
+    fn test_drain_windows_dropped_early() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Normal, DropCounter> = Sector::new();
+        repeat!(sector.push(DropCounter { counter: &counter }), 8);
+
+        let first = sector.drain_windows(3).next();
+        assert_eq!(first.map(|chunk| chunk.len()), Some(3));
+
+        assert_eq!(counter.get(), 3);
+        assert_eq!(sector.len(), 5);
+    }
+
+    #[test]
+    fn test_read_into_tail() {
+        let mut sector: Sector<Normal, u8> = Sector::from([9]);
+
+        unsafe {
+            sector.read_into_tail(10, |tail| {
+                assert_eq!(tail.len(), 10);
+                for (i, slot) in tail.iter_mut().take(5).enumerate() {
+                    slot.write(i as u8);
+                }
+                5
+            });
+        }
+
+        assert_eq!(&*sector, &[9, 0, 1, 2, 3, 4]);
+        assert!(sector.capacity() >= 11);
+    }
+
+    #[test]
+    #[should_panic(expected = "filled 3 elements but only 2 were reserved")]
+    fn test_read_into_tail_overfilled() {
+        let mut sector: Sector<Normal, u8> = Sector::new();
+        unsafe { sector.read_into_tail(2, |_| 3) };
+    }
+
+    #[test]
+    fn test_dedup_by_sorted() {
+        let mut sector: Sector<Normal, (i32, char)> =
+            Sector::from([(1, 'a'), (1, 'b'), (2, 'a'), (3, 'c'), (3, 'd'), (3, 'e')]);
+
+        sector.dedup_by_sorted(|a, b| a.0 == b.0);
+        assert_eq!(&*sector, &[(1, 'a'), (2, 'a'), (3, 'c')]);
+    }
+
+    #[test]
+    fn test_dedup_by_sorted_by_key_only() {
+        // Sorted by the first field only, the second one is out of order within the runs
+        let mut sector: Sector<Normal, (i32, char)> = Sector::from([(1, 'b'), (1, 'a'), (2, 'c')]);
+
+        sector.dedup_by_sorted(|a, b| a.0 == b.0);
+        assert_eq!(&*sector, &[(1, 'b'), (2, 'c')]);
+    }
+
+    #[test]
+    fn test_dedup_by_sorted_unsorted() {
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 1, 2, 1]);
+        sector.dedup_by_sorted(|a, b| a == b);
+        assert_eq!(&*sector, &[1, 2, 1]);
+    }
+
+    #[test]
+    fn test_take() {
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 2, 3]);
+        let ptr = unsafe { sector.as_ptr() };
+
+        let taken = sector.take();
+
+        assert_eq!(&*taken, &[1, 2, 3]);
+        assert_eq!(unsafe { taken.as_ptr() }, ptr);
+        assert!(sector.is_empty());
+        assert_eq!(sector.capacity(), 0);
+    }
+
+    #[test]
+    fn test_reserve_for_push_matches_push() {
+        for len in [0, 1, 3, 4, 7, 8] {
+            let mut reserved: Sector<Normal, i32> = Sector::new();
+            let mut pushed: Sector<Normal, i32> = Sector::new();
+            for i in 0..len {
+                reserved.push(i);
+                pushed.push(i);
+            }
+
+            reserved.reserve_for_push(1);
+            pushed.push(len);
+            assert_eq!(reserved.capacity(), pushed.capacity());
+        }
+    }
+
+    #[test]
+    fn test_reserve_for_push_more_than_len() {
+        for (len, additional) in [(4, 10), (4, 20), (3, 4), (0, 5)] {
+            let mut reserved: Sector<Normal, i32> = Sector::new();
+            let mut pushed: Sector<Normal, i32> = Sector::new();
+            for i in 0..len {
+                reserved.push(i);
+                pushed.push(i);
+            }
+
+            reserved.reserve_for_push(additional);
+            let cap = reserved.capacity();
+            for i in 0..additional {
+                pushed.push(i as i32);
+            }
+            assert_eq!(cap, pushed.capacity());
+        }
+
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 2, 3, 4]);
+        sector.reserve_for_push(20);
+        assert_eq!(sector.capacity(), 32);
+
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 2, 3, 4]);
+        sector.reserve(20);
+        assert_eq!(sector.capacity(), 24);
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        let mut sector: Sector<Normal, i32> = (1..=6).collect();
+
+        sector.retain_mut(|x| {
+            *x *= 10;
+            *x % 20 == 0
+        });
+        assert_eq!(&*sector, &[20, 40, 60]);
+    }
+
+    #[test]
+    fn test_dedup_empty_and_single() {
+        let mut sector: Sector<Normal, i32> = Sector::new();
+        sector.dedup();
+        sector.dedup_by_key(|x| *x);
+        assert!(sector.is_empty());
+
+        sector.push(1);
+        sector.dedup();
+        sector.dedup_by_key(|x| *x);
+        assert_eq!(&*sector, &[1]);
+    }
+
+    #[test]
+    fn test_dedup_drops_duplicates_once() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Normal, (i32, DropCounter)> = Sector::new();
+        for key in [1, 1, 1, 2, 3, 3] {
+            sector.push((key, DropCounter { counter: &counter }));
+        }
+
+        sector.dedup_by_key(|(key, _)| *key);
+        assert_eq!(counter.get(), 3);
+        assert_eq!(sector.iter().map(|(key, _)| *key).sum::<i32>(), 6);
+
+        drop(sector);
+        assert_eq!(counter.get(), 6);
+    }
+
+    #[test]
+    fn test_clamp_len() {
+        let mut sector: Sector<Normal, i32> = Sector::from([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        sector.clamp_len(20);
+        assert_eq!(sector.len(), 10);
+
+        sector.clamp_len(5);
+        assert_eq!(&*sector, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 2, 3]);
+        let cap = sector.capacity();
+
+        sector.fill(7);
+        assert_eq!(&*sector, &[7, 7, 7]);
+
+        let mut next = 0;
+        sector.fill_with(|| {
+            next += 1;
+            next
+        });
+        assert_eq!(&*sector, &[1, 2, 3]);
+        assert_eq!(sector.capacity(), cap);
+
+        let mut empty: Sector<Normal, i32> = Sector::new();
+        empty.fill(1);
+        empty.fill_with(|| unreachable!());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_fill_drops_old_elements() {
+        let old = core::cell::Cell::new(0);
+        let new = core::cell::Cell::new(0);
+        let mut sector: Sector<Normal, DropCounter> = Sector::new();
+        repeat!(sector.push(DropCounter { counter: &old }), 4);
+
+        sector.fill_with(|| DropCounter { counter: &new });
+        assert_eq!(old.get(), 4);
+        assert_eq!(new.get(), 0);
+        assert_eq!(sector.len(), 4);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 2, 3, 4, 5]);
+
+        sector.rotate_left(2);
+        assert_eq!(&*sector, &[3, 4, 5, 1, 2]);
+        sector.rotate_right(3);
+        assert_eq!(&*sector, &[5, 1, 2, 3, 4]);
+        sector.rotate_left(5);
+        assert_eq!(&*sector, &[5, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_out_of_bounds() {
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 2, 3, 4, 5]);
+        sector.rotate_right(6);
+    }
+
+    #[test]
+    fn test_partition_point() {
+        let sector: Sector<Normal, i32> = Sector::from([1, 2, 3, 7, 8]);
+        assert_eq!(sector.partition_point(|x| *x < 5), 3);
+        assert_eq!(sector.partition_point(|x| *x < 0), 0);
+        assert_eq!(sector.partition_point(|x| *x < 10), 5);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut sector: Sector<Normal, i32> = Sector::from([1, 2, 3, 4]);
+        sector.reverse();
+        assert_eq!(&*sector, &[4, 3, 2, 1]);
+
+        let mut single: Sector<Normal, i32> = Sector::from([1]);
+        single.reverse();
+        assert_eq!(&*single, &[1]);
+
+        let mut empty: Sector<Normal, i32> = Sector::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+
+        let mut zsts: Sector<Normal, ZeroSizedType> = Sector::new();
+        repeat!(zsts.push(ZeroSizedType), 3);
+        zsts.reverse();
+        assert_eq!(zsts.len(), 3);
+    }
 }
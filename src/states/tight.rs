@@ -15,9 +15,16 @@
 //!   shrinks by the precise number of elements removed, releasing any unneeded capacity.
 //!
 //! All other operations (such as `push`, `pop`, `insert`, and `remove`) behave as in other states.
-use core::ptr::NonNull;
+use core::{
+    ops::RangeBounds,
+    ptr::NonNull,
+    slice::{IterMut, SliceIndex},
+};
 
-use crate::components::{Cap, Grow, Index, Insert, Len, Pop, Ptr, Push, Remove, Shrink};
+use crate::components::{
+    Cap, Clear, Dedup, ExtendFrom, Grow, Index, Insert, Len, Pop, Ptr, Push, Remove, Reserve,
+    Retain, Shrink, ShrinkTo, Truncate,
+};
 
 use crate::Sector;
 
@@ -27,6 +34,10 @@ impl crate::components::DefaultIter for Tight {}
 
 impl crate::components::DefaultDrain for Tight {}
 
+impl super::StateName for Tight {
+    const NAME: &'static str = "Tight";
+}
+
 impl<T> Sector<Tight, T> {
     /// Appends an element to the end of the sector.
     ///
@@ -63,14 +74,255 @@ impl<T> Sector<Tight, T> {
         self.__remove(index)
     }
 
-    /// Returns a reference to the element at the given index if it exists.
-    pub fn get(&self, index: usize) -> Option<&T> {
-        self.__get(index)
+    /// Returns a reference to an element or subslice depending on the type of index.
+    ///
+    /// - If given a position, returns a reference to the element at that position or `None` if
+    ///   out of bounds.
+    /// - If given a range, returns the subslice corresponding to that range, or `None` if out of
+    ///   bounds.
+    pub fn get<I: SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+        (**self).get(index)
+    }
+
+    /// Returns a mutable reference to an element or subslice depending on the type of index
+    /// (see [`get`](Self::get)) or `None` if the index is out of bounds.
+    pub fn get_mut<I: SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+        (**self).get_mut(index)
+    }
+
+    /// Shortens the sector, keeping the first `new_len` elements and dropping the rest.
+    ///
+    /// Has no effect if `new_len` is greater or equal to the current length.
+    ///
+    /// The allocation shrinks to exactly `new_len` in a single reallocation, instead of one per
+    /// removed element like repeated [`pop`](Self::pop)s would.
+    pub fn truncate(&mut self, new_len: usize) {
+        self.__truncate(new_len);
+    }
+
+    /// Keeps the first `n` elements and drops the rest.
+    ///
+    /// This is an alias for [`truncate`](Self::truncate).
+    pub fn keep_first(&mut self, n: usize) {
+        self.__truncate(n);
+    }
+
+    /// Keeps the last `n` elements and drops the rest, shifting the kept elements to the front.
+    ///
+    /// Has no effect if `n` is greater or equal to the current length.
+    pub fn keep_last(&mut self, n: usize) {
+        self.__truncate_front(n);
+    }
+
+    /// Removes all but the first of consecutive elements that resolve to the same key.
+    ///
+    /// `key` gets called exactly once per element.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, key: F) {
+        self.__dedup_by_key(key);
+    }
+
+    /// Clones the elements in `src` and appends them to the end of the sector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` is out of bounds or its start is greater than its end.
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R)
+    where
+        T: Clone,
+    {
+        self.__extend_from_within(src);
+    }
+
+    /// Drops all elements, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.__clear();
+    }
+
+    /// Removes consecutive repeated elements.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.__dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive repeated elements, like [`dedup`](Self::dedup), but faster for `Copy`
+    /// types.
+    pub fn dedup_copy(&mut self)
+    where
+        T: Copy + PartialEq,
+    {
+        self.__dedup_copy();
+    }
+
+    /// Copies all elements of `other` to the end of the sector, growing at most once.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Copy,
+    {
+        self.__extend_from_slice(other);
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving their order.
+    ///
+    /// Rejected elements are dropped right away, in a single pass over the sector.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.__retain_mut(|elem| f(elem));
+    }
+
+    /// Keeps only the elements within `range` for which `f` returns `true`, leaving the elements
+    /// outside of `range` untouched.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds or its start is greater than its end.
+    pub fn retain_range<R: RangeBounds<usize>, F: FnMut(&T) -> bool>(
+        &mut self,
+        range: R,
+        mut f: F,
+    ) {
+        self.__retain_range_mut(range, |elem| f(elem));
+    }
+
+    /// Returns an iterator over mutable references to all elements.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        (**self).iter_mut()
+    }
+
+    /// Shortens the sector to `new_len` elements, dropping the rest.
+    ///
+    /// Unlike a resize this can never grow the sector: if `new_len` is greater or equal to the
+    /// current length, nothing happens.
+    pub fn shorten_to(&mut self, new_len: usize) {
+        self.__truncate(new_len);
+    }
+
+    /// Reclaims unused memory by shrinking the capacity to exactly the length.
+    pub fn compact(&mut self) {
+        self.__shrink_to_fit();
+    }
+
+    /// Returns the elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    /// Returns the elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    /// Returns `true` if the sector contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        (**self).contains(x)
+    }
+
+    /// Binary searches the sorted sector for `x`.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index where `x` could
+    /// be inserted while keeping the order. See [`slice::binary_search`] for details.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        (**self).binary_search(x)
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`.
+    ///
+    /// The sector has to be partitioned by `pred`, i.e. all elements it accepts come first. See
+    /// [`slice::partition_point`] for details.
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, pred: F) -> usize {
+        (**self).partition_point(pred)
+    }
+
+    /// Drops elements from the back as long as `pred` returns `true` for the last one.
+    ///
+    /// The matching elements are removed at once, so the sector shrinks at most once.
+    ///
+    /// # Returns
+    ///
+    /// The number of removed elements.
+    pub fn pop_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+        let count = self.iter().rev().take_while(|elem| pred(elem)).count();
+        self.__truncate(self.__len() - count);
+        count
+    }
+
+    /// Removes all but the first of consecutive elements that `eq` considers equal, assuming
+    /// the sector is sorted by whatever `eq` compares, e.g. a key.
+    ///
+    /// Sorted input keeps all equal elements next to each other, so a single O(n) pass removes
+    /// every duplicate. `eq` gets passed the kept element first. The order is not checked, on
+    /// unsorted input only consecutive duplicates get removed.
+    pub fn dedup_by_sorted<F: FnMut(&T, &T) -> bool>(&mut self, mut eq: F) {
+        self.__dedup_by(|current, kept| eq(kept, current));
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving their order.
+    ///
+    /// Like [`retain`](Self::retain), but `f` may also modify the elements it inspects.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        self.__retain_mut(f);
+    }
+
+    /// Caps the sector at `max_len` elements, dropping the excess tail.
+    ///
+    /// Does nothing if the sector already holds at most `max_len` elements.
+    pub fn clamp_len(&mut self, max_len: usize) {
+        self.__truncate(max_len);
+    }
+
+    /// Overwrites every element with clones of `value`, dropping the old elements.
+    ///
+    /// Length and capacity stay the same.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        (**self).fill(value);
+    }
+
+    /// Overwrites every element with the values returned by `f`, dropping the old elements.
+    ///
+    /// Length and capacity stay the same.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        (**self).fill_with(f);
+    }
+
+    /// Rotates the elements in place so the element at `mid` becomes the first one.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `mid` is greater than the length.
+    pub fn rotate_left(&mut self, mid: usize) {
+        (**self).rotate_left(mid);
+    }
+
+    /// Rotates the elements in place so the last `k` elements move to the front.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `k` is greater than the length.
+    pub fn rotate_right(&mut self, k: usize) {
+        (**self).rotate_right(k);
+    }
+
+    /// Reverses the order of the elements in place.
+    pub fn reverse(&mut self) {
+        (**self).reverse();
     }
+}
+
+impl<'a, T> IntoIterator for &'a mut Sector<Tight, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
 
-    /// Returns a mutable reference to the element at the given index if it exists.
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        self.__get_mut(index)
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
@@ -172,6 +424,13 @@ impl<T> Pop<T> for Sector<Tight, T> {}
 impl<T> Insert<T> for Sector<Tight, T> {}
 impl<T> Index<T> for Sector<Tight, T> {}
 impl<T> Remove<T> for Sector<Tight, T> {}
+impl<T> Truncate<T> for Sector<Tight, T> {}
+impl<T> Dedup<T> for Sector<Tight, T> {}
+impl<T> Reserve<T> for Sector<Tight, T> {}
+impl<T> ExtendFrom<T> for Sector<Tight, T> {}
+impl<T> Clear<T> for Sector<Tight, T> {}
+impl<T> Retain<T> for Sector<Tight, T> {}
+impl<T> ShrinkTo<T> for Sector<Tight, T> {}
 
 #[cfg(test)]
 mod tests {
@@ -749,4 +1008,535 @@ mod tests {
         sector.pop();
         assert_eq!(sector.capacity(), 0);
     }
+
+    #[test]
+    fn test_get_range() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+
+        sector.push(10);
+        sector.push(20);
+        sector.push(30);
+
+        assert_eq!(sector.get(1..3), Some(&[20, 30][..]));
+        assert_eq!(sector.get(..), Some(&[10, 20, 30][..]));
+        assert_eq!(sector.get(2..4), None);
+        assert_eq!(sector.get(3), None);
+
+        if let Some(slice) = sector.get_mut(..2) {
+            slice[1] = 25;
+        }
+        assert_eq!(sector.get(1), Some(&25));
+        assert_eq!(sector.get_mut(2..=3), None);
+    }
+
+    #[test]
+    fn test_keep_first() {
+        let mut sector: Sector<Tight, char> = Sector::new();
+        sector.push('a');
+        sector.push('b');
+        sector.push('c');
+        sector.push('d');
+
+        sector.keep_first(2);
+        assert_eq!(&*sector, &['a', 'b']);
+
+        sector.keep_first(5);
+        assert_eq!(&*sector, &['a', 'b']);
+    }
+
+    #[test]
+    fn test_keep_last() {
+        let mut sector: Sector<Tight, char> = Sector::new();
+        sector.push('a');
+        sector.push('b');
+        sector.push('c');
+        sector.push('d');
+
+        sector.keep_last(2);
+        assert_eq!(&*sector, &['c', 'd']);
+
+        sector.keep_last(5);
+        assert_eq!(&*sector, &['c', 'd']);
+
+        sector.keep_last(0);
+        assert_eq!(sector.len(), 0);
+    }
+
+    #[test]
+    fn test_truncate_drop() {
+        let counter = core::cell::Cell::new(0);
+        {
+            let mut sector: Sector<Tight, DropCounter> = Sector::new();
+            repeat!(sector.push(DropCounter { counter: &counter }), 6);
+
+            sector.truncate(4);
+            assert_eq!(counter.get(), 2);
+
+            sector.keep_last(1);
+            assert_eq!(counter.get(), 5);
+            assert_eq!(sector.len(), 1);
+        }
+        assert_eq!(counter.get(), 6);
+    }
+
+    #[test]
+    fn test_dedup_by_key() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        for i in [10, 11, 20, 21, 22, 30, 10, 12] {
+            sector.push(i);
+        }
+
+        sector.dedup_by_key(|x| *x / 10);
+        assert_eq!(&*sector, &[10, 20, 30, 10]);
+    }
+
+    #[test]
+    fn test_dedup_by_key_calls_key_once() {
+        let calls = core::cell::Cell::new(0);
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        repeat!(sector.push(1), 1000);
+        sector.push(2);
+
+        sector.dedup_by_key(|x| {
+            calls.set(calls.get() + 1);
+            *x
+        });
+
+        assert_eq!(&*sector, &[1, 2]);
+        assert_eq!(calls.get(), 1001);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 5) should be <= len (is 3)")]
+    fn test_insert_past_end() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        repeat!(sector.push(1), 3);
+
+        sector.insert(5, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index (is 3) should be < len (is 3)")]
+    fn test_remove_out_of_bounds() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        repeat!(sector.push(1), 3);
+
+        sector.remove(3);
+    }
+
+    #[test]
+    fn test_extend_from_within() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        for i in 0..4 {
+            sector.push(i);
+        }
+
+        sector.extend_from_within(1..3);
+        assert_eq!(&*sector, &[0, 1, 2, 3, 1, 2]);
+    }
+
+    #[test]
+    fn test_extend_from_within_reallocating() {
+        let mut sector: Sector<Tight, String> = Sector::new();
+        for s in ["a", "b", "c", "d"] {
+            sector.push(s.into());
+        }
+        let len = sector.len();
+        assert_eq!(sector.capacity(), len);
+
+        sector.extend_from_within(0..len);
+        sector.extend_from_within(..);
+        assert_eq!(
+            &*sector,
+            &["a", "b", "c", "d", "a", "b", "c", "d", "a", "b", "c", "d", "a", "b", "c", "d"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 5 out of range for slice of length 4")]
+    fn test_extend_from_within_out_of_bounds() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        repeat!(sector.push(1), 4);
+
+        sector.extend_from_within(2..5);
+    }
+
+    #[test]
+    fn test_truncate_shrinks_exactly() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        for i in 0..1000 {
+            sector.push(i);
+        }
+        assert_eq!(sector.capacity(), 1000);
+
+        sector.truncate(10);
+        assert_eq!(sector.len(), 10);
+        assert_eq!(sector.capacity(), 10);
+        assert_eq!(&*sector, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        sector.truncate(0);
+        assert_eq!(sector.capacity(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn test_truncate_reallocates_once() {
+        use std::{cell::RefCell, rc::Rc, vec::Vec};
+
+        let mut sector: Sector<Tight, i32> = Sector::with_capacity(1000);
+        repeat!(sector.push(0), 1000);
+
+        let recorded = Rc::new(RefCell::new(Vec::new()));
+        let hook_recorded = Rc::clone(&recorded);
+        crate::metrics::set_realloc_hook(move |old, new| {
+            hook_recorded.borrow_mut().push((old, new))
+        });
+        sector.truncate(10);
+        crate::metrics::clear_realloc_hook();
+
+        assert_eq!(*recorded.borrow(), [(1000, 10)]);
+    }
+
+    #[test]
+    fn test_clear() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Tight, DropCounter> = Sector::with_capacity(8);
+        repeat!(sector.push(DropCounter { counter: &counter }), 8);
+        let cap = sector.capacity();
+
+        sector.clear();
+        assert_eq!(counter.get(), 8);
+        assert_eq!(sector.len(), 0);
+        assert_eq!(sector.capacity(), cap);
+    }
+
+    #[test]
+    fn test_clear_zst() {
+        let mut sector: Sector<Tight, ZeroSizedType> = Sector::new();
+        repeat!(sector.push(ZeroSizedType), 5);
+
+        sector.clear();
+        assert_eq!(sector.len(), 0);
+        assert_eq!(sector.capacity(), usize::MAX);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut sector: Sector<Tight, String> = Sector::new();
+        for s in ["a", "a", "b", "a", "c", "c", "c"] {
+            sector.push(s.into());
+        }
+
+        sector.dedup();
+        assert_eq!(&*sector, &["a", "b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_dedup_copy_matches_dedup() {
+        let mut generic: Sector<Tight, u32> = Sector::new();
+        let mut copy: Sector<Tight, u32> = Sector::new();
+        for i in 0..10_000u32 {
+            let value = i / 3 % 7;
+            generic.push(value);
+            copy.push(value);
+        }
+
+        generic.dedup();
+        copy.dedup_copy();
+        assert_eq!(&*copy, &*generic);
+        assert_eq!(copy.len(), 3334);
+    }
+
+    #[test]
+    fn test_extend_from_slice() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        sector.push(0);
+
+        sector.extend_from_slice(&[1, 2, 3, 4]);
+        sector.extend_from_slice(&[]);
+        assert_eq!(&*sector, &[0, 1, 2, 3, 4]);
+        assert!(sector.capacity() >= 5);
+    }
+
+    #[test]
+    fn test_extend_from_slice_exact_capacity() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        sector.push(0);
+
+        sector.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(&*sector, &[0, 1, 2, 3]);
+        assert_eq!(sector.capacity(), 4);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        for i in 0..10 {
+            sector.push(i);
+        }
+
+        sector.retain(|x| x % 2 == 1);
+        assert_eq!(&*sector, &[1, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn test_retain_shrinks_exactly() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        for i in 0..10 {
+            sector.push(i);
+        }
+
+        sector.retain(|x| *x < 3);
+        assert_eq!(&*sector, &[0, 1, 2]);
+        assert_eq!(sector.capacity(), 3);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut sector: Sector<Tight, i32> = Sector::with_capacity(3);
+        for i in 1..=3 {
+            sector.push(i);
+        }
+
+        for elem in sector.iter_mut() {
+            *elem *= 10;
+        }
+        assert_eq!(&*sector, &[10, 20, 30]);
+    }
+
+    #[test]
+    fn test_shorten_to() {
+        let mut sector: Sector<Tight, i32> = Sector::with_capacity(5);
+        for i in 0..5 {
+            sector.push(i);
+        }
+
+        sector.shorten_to(7);
+        assert_eq!(sector.len(), 5);
+        sector.shorten_to(5);
+        assert_eq!(sector.len(), 5);
+
+        sector.shorten_to(2);
+        assert_eq!(&*sector, &[0, 1]);
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut sector: Sector<Tight, i32> = Sector::with_capacity(16);
+        for i in 0..4 {
+            sector.push(i);
+        }
+
+        sector.compact();
+        assert_eq!(sector.capacity(), 4);
+        assert_eq!(&*sector, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let mut sector: Sector<Tight, i32> = Sector::from([3, 1, 2]);
+
+        sector.as_mut_slice().sort();
+        assert_eq!(sector.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_retain_range() {
+        let mut sector: Sector<Tight, i32> = (0..10).collect();
+
+        sector.retain_range(2..6, |x| x % 2 == 0);
+        assert_eq!(&*sector, &[0, 1, 2, 4, 6, 7, 8, 9]);
+
+        sector.retain_range(6.., |_| false);
+        assert_eq!(&*sector, &[0, 1, 2, 4, 6, 7]);
+
+        sector.retain_range(..0, |_| false);
+        assert_eq!(sector.len(), 6);
+    }
+
+    #[test]
+    fn test_contains_and_binary_search() {
+        let sector: Sector<Tight, i32> = Sector::from([1, 3, 5, 7]);
+
+        assert!(sector.contains(&5));
+        assert!(!sector.contains(&4));
+        assert_eq!(sector.binary_search(&7), Ok(3));
+        assert_eq!(sector.binary_search(&4), Err(2));
+        assert_eq!(sector.binary_search(&0), Err(0));
+    }
+
+    #[test]
+    fn test_into_iter_by_reference() {
+        let mut sector: Sector<Tight, i32> = Sector::from([1, 2, 3]);
+
+        for elem in &mut sector {
+            *elem *= 10;
+        }
+
+        let mut sum = 0;
+        for elem in &sector {
+            sum += elem;
+        }
+        assert_eq!(sum, 60);
+    }
+
+    #[test]
+    fn test_pop_while() {
+        let mut sector: Sector<Tight, i32> = Sector::from([1, 2, 0, 0, 0]);
+
+        assert_eq!(sector.pop_while(|x| *x == 0), 3);
+        assert_eq!(&*sector, &[1, 2]);
+
+        assert_eq!(sector.pop_while(|x| *x == 0), 0);
+        assert_eq!(sector.pop_while(|_| true), 2);
+        assert!(sector.is_empty());
+    }
+
+    #[test]
+    fn test_dedup_by_sorted() {
+        let mut sector: Sector<Tight, (i32, char)> =
+            Sector::from([(1, 'a'), (1, 'b'), (2, 'a'), (3, 'c'), (3, 'd'), (3, 'e')]);
+
+        sector.dedup_by_sorted(|a, b| a.0 == b.0);
+        assert_eq!(&*sector, &[(1, 'a'), (2, 'a'), (3, 'c')]);
+    }
+
+    #[test]
+    fn test_dedup_by_sorted_by_key_only() {
+        // Sorted by the first field only, the second one is out of order within the runs
+        let mut sector: Sector<Tight, (i32, char)> = Sector::from([(1, 'b'), (1, 'a'), (2, 'c')]);
+
+        sector.dedup_by_sorted(|a, b| a.0 == b.0);
+        assert_eq!(&*sector, &[(1, 'b'), (2, 'c')]);
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        let mut sector: Sector<Tight, i32> = (1..=6).collect();
+
+        sector.retain_mut(|x| {
+            *x *= 10;
+            *x % 20 == 0
+        });
+        assert_eq!(&*sector, &[20, 40, 60]);
+    }
+
+    #[test]
+    fn test_dedup_empty_and_single() {
+        let mut sector: Sector<Tight, i32> = Sector::new();
+        sector.dedup();
+        sector.dedup_by_key(|x| *x);
+        assert!(sector.is_empty());
+
+        sector.push(1);
+        sector.dedup();
+        sector.dedup_by_key(|x| *x);
+        assert_eq!(&*sector, &[1]);
+    }
+
+    #[test]
+    fn test_dedup_drops_duplicates_once() {
+        let counter = core::cell::Cell::new(0);
+        let mut sector: Sector<Tight, (i32, DropCounter)> = Sector::new();
+        for key in [1, 1, 1, 2, 3, 3] {
+            sector.push((key, DropCounter { counter: &counter }));
+        }
+
+        sector.dedup_by_key(|(key, _)| *key);
+        assert_eq!(counter.get(), 3);
+        assert_eq!(sector.iter().map(|(key, _)| *key).sum::<i32>(), 6);
+
+        drop(sector);
+        assert_eq!(counter.get(), 6);
+    }
+
+    #[test]
+    fn test_dedup_shrinks_capacity() {
+        let mut sector: Sector<Tight, i32> = Sector::from([1, 1, 2, 2, 2, 3]);
+        assert_eq!(sector.capacity(), 6);
+
+        sector.dedup();
+        assert_eq!(&*sector, &[1, 2, 3]);
+        assert_eq!(sector.capacity(), 3);
+    }
+
+    #[test]
+    fn test_clamp_len() {
+        let mut sector: Sector<Tight, i32> = Sector::from([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        sector.clamp_len(20);
+        assert_eq!(sector.len(), 10);
+
+        sector.clamp_len(5);
+        assert_eq!(&*sector, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut sector: Sector<Tight, i32> = Sector::from([1, 2, 3]);
+        let cap = sector.capacity();
+
+        sector.fill(7);
+        assert_eq!(&*sector, &[7, 7, 7]);
+
+        let mut next = 0;
+        sector.fill_with(|| {
+            next += 1;
+            next
+        });
+        assert_eq!(&*sector, &[1, 2, 3]);
+        assert_eq!(sector.capacity(), cap);
+
+        let mut empty: Sector<Tight, i32> = Sector::new();
+        empty.fill(1);
+        empty.fill_with(|| unreachable!());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut sector: Sector<Tight, i32> = Sector::from([1, 2, 3, 4, 5]);
+
+        sector.rotate_left(2);
+        assert_eq!(&*sector, &[3, 4, 5, 1, 2]);
+        sector.rotate_right(3);
+        assert_eq!(&*sector, &[5, 1, 2, 3, 4]);
+        sector.rotate_left(5);
+        assert_eq!(&*sector, &[5, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_out_of_bounds() {
+        let mut sector: Sector<Tight, i32> = Sector::from([1, 2, 3, 4, 5]);
+        sector.rotate_right(6);
+    }
+
+    #[test]
+    fn test_partition_point() {
+        let sector: Sector<Tight, i32> = Sector::from([1, 2, 3, 7, 8]);
+        assert_eq!(sector.partition_point(|x| *x < 5), 3);
+        assert_eq!(sector.partition_point(|x| *x < 0), 0);
+        assert_eq!(sector.partition_point(|x| *x < 10), 5);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut sector: Sector<Tight, i32> = Sector::from([1, 2, 3, 4]);
+        sector.reverse();
+        assert_eq!(&*sector, &[4, 3, 2, 1]);
+
+        let mut single: Sector<Tight, i32> = Sector::from([1]);
+        single.reverse();
+        assert_eq!(&*single, &[1]);
+
+        let mut empty: Sector<Tight, i32> = Sector::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+
+        let mut zsts: Sector<Tight, ZeroSizedType> = Sector::new();
+        repeat!(zsts.push(ZeroSizedType), 3);
+        zsts.reverse();
+        assert_eq!(zsts.len(), 3);
+    }
 }
@@ -13,6 +13,12 @@
 //! [`core::mem::forget`] to prevent the old sector from running its destructor. This way, the transition
 //! is efficient and does not involve memory reallocation.
 //!
+//! The plain `to_*` transitions keep the exact same allocation: `capacity()`, `len()` and the
+//! elements are identical before and after the conversion. Transitions that resize on the way, such
+//! as `to_dynamic_tight` or `to_fixed_with_capacity`, say so in their docs. For zero-sized types,
+//! `to_fixed` additionally keeps the logical capacity that `Fixed` enforces, which `capacity()` does
+//! not report.
+//!
 //! ## Safety and Invariants
 //!
 //! These conversions assume that the target state's invariants are compatible with the current sector's
@@ -32,14 +38,15 @@
 //!
 //! The following implementations provide state-specific conversion methods. Each method is an inline wrapper
 //! around the generic [`to_custom`] method.
+use crate::components::{Cap, Grow, Shrink};
 use crate::Sector;
 
 impl<T, State> Sector<State, T> {
     /// Generic conversion method to transform the current sector into one with a new state.
     ///
-    /// This method performs a bitwise copy of the internal buffer (`buf`), current length (`len`), and
-    /// state marker, then transfers ownership to a new sector of type `Sector<Target, T>`. The original
-    /// sector is forgotten to avoid running its destructor.
+    /// This method performs a bitwise copy of the internal buffer (`buf`), current length (`len`),
+    /// minimum capacity (`min_cap`) and state marker, then transfers ownership to a new sector of
+    /// type `Sector<Target, T>`. The original sector is forgotten to avoid running its destructor.
     ///
     /// # Safety
     ///
@@ -49,11 +56,66 @@ impl<T, State> Sector<State, T> {
         let new_sector = Sector {
             buf: unsafe { core::ptr::read(&self.buf) },
             len: self.len,
+            min_cap: self.min_cap,
             _state: core::marker::PhantomData,
         };
         core::mem::forget(self);
         new_sector
     }
+
+    /// Converts the sector into a `Fixed` sector with exactly `cap` elements of capacity,
+    /// growing or shrinking the allocation first if needed.
+    ///
+    /// Zero-sized types never allocate, so for them only the logical capacity is set.
+    ///
+    /// # Panics
+    ///
+    /// - if `cap` is smaller than the current length
+    /// - if the allocation fails
+    fn resize_to_fixed(self, cap: usize) -> Sector<super::Fixed, T> {
+        assert!(
+            cap >= self.len,
+            "fixed capacity (is {cap}) should be >= len (is {})",
+            self.len
+        );
+
+        let mut fixed: Sector<super::Fixed, T> = Self::to_custom(self);
+        if core::mem::size_of::<T>() == 0 {
+            fixed.buf.zst_cap = cap;
+        } else {
+            let old_cap = fixed.__cap();
+            if cap > old_cap {
+                fixed.__grow_manually_unchecked(cap - old_cap);
+            } else if cap < old_cap {
+                fixed.__shrink_manually_unchecked(old_cap - cap);
+            }
+        }
+        fixed
+    }
+
+    /// Converts the sector into a `Fixed` sector if it can hold at least one element.
+    ///
+    /// A `Fixed` sector never reallocates, so one without capacity could never accept a push.
+    /// In that case the sector is returned unchanged in `Err`.
+    fn try_resize_to_fixed(self) -> Result<Sector<super::Fixed, T>, Self> {
+        let cap = self.fixed_capacity();
+        if cap == 0 {
+            return Err(self);
+        }
+        Ok(self.resize_to_fixed(cap))
+    }
+
+    /// The capacity `to_fixed` keeps.
+    ///
+    /// Zero-sized types report a `capacity()` of `usize::MAX`, so their logical capacity is used
+    /// instead. Otherwise a limit set by `Fixed` or `Manual` would get lost on the way back.
+    fn fixed_capacity(&self) -> usize {
+        if core::mem::size_of::<T>() == 0 {
+            core::cmp::max(self.buf.zst_cap, self.len)
+        } else {
+            self.capacity()
+        }
+    }
 }
 
 impl<T> Sector<super::Normal, T> {
@@ -62,11 +124,60 @@ impl<T> Sector<super::Normal, T> {
         Self::to_custom(self)
     }
 
-    /// Converts a `Normal` sector to a `Fixed` sector.
-    pub fn to_fixed(self) -> Sector<super::Fixed, T> {
+    /// Converts a `Normal` sector to a `Dynamic` sector, which shrinks automatically when elements
+    /// get removed.
+    ///
+    /// This is the same as [`to_dynamic`](Self::to_dynamic).
+    pub fn to_shrinking(self) -> Sector<super::Dynamic, T> {
         Self::to_custom(self)
     }
 
+    /// Converts a `Normal` sector to a `Dynamic` sector and shrinks its capacity to its length.
+    ///
+    /// Useful to reclaim the memory of a sector that grew large once but holds few elements now.
+    /// Unlike the plain transitions this reallocates, unless the sector is already full.
+    pub fn to_dynamic_tight(self) -> Sector<super::Dynamic, T> {
+        let mut dynamic: Sector<super::Dynamic, T> = Self::to_custom(self);
+        dynamic.min_cap = dynamic.min_cap.min(dynamic.len);
+
+        let excess = dynamic.__cap() - dynamic.len;
+        if excess > 0 && core::mem::size_of::<T>() != 0 {
+            dynamic.__shrink_manually_unchecked(excess);
+        }
+        dynamic
+    }
+
+    /// Converts a `Normal` sector to a `Fixed` sector, keeping its current capacity.
+    ///
+    /// Use [`try_to_fixed`](Self::try_to_fixed) to reject sectors without capacity, or
+    /// [`to_fixed_with_capacity`](Self::to_fixed_with_capacity) to choose the capacity.
+    pub fn to_fixed(self) -> Sector<super::Fixed, T> {
+        let cap = self.fixed_capacity();
+        self.resize_to_fixed(cap)
+    }
+
+    /// Converts a `Normal` sector to a `Fixed` sector, keeping its current capacity.
+    ///
+    /// Fails and returns the sector unchanged if its capacity is `0`, as the resulting `Fixed`
+    /// sector could never hold an element.
+    pub fn try_to_fixed(self) -> Result<Sector<super::Fixed, T>, Self> {
+        self.try_resize_to_fixed()
+    }
+
+    /// Converts a `Normal` sector to a `Fixed` sector with a capacity of exactly `cap`, growing or
+    /// shrinking the allocation before the transition.
+    ///
+    /// Unlike the plain transitions this reallocates whenever `cap` differs from the current
+    /// capacity. Zero-sized types never allocate, for them only the enforced capacity is set.
+    ///
+    /// # Panics
+    ///
+    /// - if `cap` is smaller than the current length
+    /// - if the allocation fails
+    pub fn to_fixed_with_capacity(self, cap: usize) -> Sector<super::Fixed, T> {
+        self.resize_to_fixed(cap)
+    }
+
     /// Converts a `Normal` sector to a `Tight` sector.
     pub fn to_tight(self) -> Sector<super::Tight, T> {
         Self::to_custom(self)
@@ -89,9 +200,35 @@ impl<T> Sector<super::Dynamic, T> {
         Self::to_custom(self)
     }
 
-    /// Converts a `Dynamic` sector to a `Fixed` sector.
+    /// Converts a `Dynamic` sector to a `Fixed` sector, keeping its current capacity.
+    ///
+    /// Use [`try_to_fixed`](Self::try_to_fixed) to reject sectors without capacity, or
+    /// [`to_fixed_with_capacity`](Self::to_fixed_with_capacity) to choose the capacity.
     pub fn to_fixed(self) -> Sector<super::Fixed, T> {
-        Self::to_custom(self)
+        let cap = self.fixed_capacity();
+        self.resize_to_fixed(cap)
+    }
+
+    /// Converts a `Dynamic` sector to a `Fixed` sector, keeping its current capacity.
+    ///
+    /// Fails and returns the sector unchanged if its capacity is `0`, as the resulting `Fixed`
+    /// sector could never hold an element.
+    pub fn try_to_fixed(self) -> Result<Sector<super::Fixed, T>, Self> {
+        self.try_resize_to_fixed()
+    }
+
+    /// Converts a `Dynamic` sector to a `Fixed` sector with a capacity of exactly `cap`, growing or
+    /// shrinking the allocation before the transition.
+    ///
+    /// Unlike the plain transitions this reallocates whenever `cap` differs from the current
+    /// capacity. Zero-sized types never allocate, for them only the enforced capacity is set.
+    ///
+    /// # Panics
+    ///
+    /// - if `cap` is smaller than the current length
+    /// - if the allocation fails
+    pub fn to_fixed_with_capacity(self, cap: usize) -> Sector<super::Fixed, T> {
+        self.resize_to_fixed(cap)
     }
 
     /// Converts a `Dynamic` sector to a `Tight` sector.
@@ -153,15 +290,61 @@ impl<T> Sector<super::Locked, T> {
         Self::to_custom(self)
     }
 
-    /// Converts a `Locked` sector to a `Fixed` sector.
+    /// Converts a `Locked` sector to a `Fixed` sector, keeping its current capacity.
+    ///
+    /// Use [`try_to_fixed`](Self::try_to_fixed) to reject sectors without capacity, or
+    /// [`to_fixed_with_capacity`](Self::to_fixed_with_capacity) to choose the capacity.
     pub fn to_fixed(self) -> Sector<super::Fixed, T> {
-        Self::to_custom(self)
+        let cap = self.fixed_capacity();
+        self.resize_to_fixed(cap)
+    }
+
+    /// Converts a `Locked` sector to a `Fixed` sector, keeping its current capacity.
+    ///
+    /// Fails and returns the sector unchanged if its capacity is `0`, as the resulting `Fixed`
+    /// sector could never hold an element.
+    pub fn try_to_fixed(self) -> Result<Sector<super::Fixed, T>, Self> {
+        self.try_resize_to_fixed()
+    }
+
+    /// Converts a `Locked` sector to a `Fixed` sector with a capacity of exactly `cap`, growing or
+    /// shrinking the allocation before the transition.
+    ///
+    /// Unlike the plain transitions this reallocates whenever `cap` differs from the current
+    /// capacity. Zero-sized types never allocate, for them only the enforced capacity is set.
+    ///
+    /// # Panics
+    ///
+    /// - if `cap` is smaller than the current length
+    /// - if the allocation fails
+    pub fn to_fixed_with_capacity(self, cap: usize) -> Sector<super::Fixed, T> {
+        self.resize_to_fixed(cap)
     }
 
     /// Converts a `Locked` sector to a `Manual` sector.
     pub fn to_manual(self) -> Sector<super::Manual, T> {
         Self::to_custom(self)
     }
+
+    /// Unlocks the sector, turning it back into a `Normal` sector.
+    ///
+    /// This is the same as [`to_normal`](Self::to_normal).
+    pub fn unlock(self) -> Sector<super::Normal, T> {
+        Self::to_custom(self)
+    }
+
+    /// Temporarily unlocks the sector to run `f` on it as a `Normal` sector, then locks it again.
+    ///
+    /// Returns the re-locked sector together with the result of `f`. The capacity may have changed
+    /// if `f` grew or shrank the sector.
+    pub fn with_unlocked<R, F: FnOnce(&mut Sector<super::Normal, T>) -> R>(
+        self,
+        f: F,
+    ) -> (Sector<super::Locked, T>, R) {
+        let mut normal = self.unlock();
+        let result = f(&mut normal);
+        (normal.to_locked(), result)
+    }
 }
 
 impl<T> Sector<super::Manual, T> {
@@ -180,9 +363,35 @@ impl<T> Sector<super::Manual, T> {
         Self::to_custom(self)
     }
 
-    /// Converts a `Manual` sector to a `Fixed` sector.
+    /// Converts a `Manual` sector to a `Fixed` sector, keeping its current capacity.
+    ///
+    /// Use [`try_to_fixed`](Self::try_to_fixed) to reject sectors without capacity, or
+    /// [`to_fixed_with_capacity`](Self::to_fixed_with_capacity) to choose the capacity.
     pub fn to_fixed(self) -> Sector<super::Fixed, T> {
-        Self::to_custom(self)
+        let cap = self.fixed_capacity();
+        self.resize_to_fixed(cap)
+    }
+
+    /// Converts a `Manual` sector to a `Fixed` sector, keeping its current capacity.
+    ///
+    /// Fails and returns the sector unchanged if its capacity is `0`, as the resulting `Fixed`
+    /// sector could never hold an element.
+    pub fn try_to_fixed(self) -> Result<Sector<super::Fixed, T>, Self> {
+        self.try_resize_to_fixed()
+    }
+
+    /// Converts a `Manual` sector to a `Fixed` sector with a capacity of exactly `cap`, growing or
+    /// shrinking the allocation before the transition.
+    ///
+    /// Unlike the plain transitions this reallocates whenever `cap` differs from the current
+    /// capacity. Zero-sized types never allocate, for them only the enforced capacity is set.
+    ///
+    /// # Panics
+    ///
+    /// - if `cap` is smaller than the current length
+    /// - if the allocation fails
+    pub fn to_fixed_with_capacity(self, cap: usize) -> Sector<super::Fixed, T> {
+        self.resize_to_fixed(cap)
     }
 
     /// Converts a `Manual` sector to a `Locked` sector.
@@ -201,9 +410,35 @@ impl<T> Sector<super::Tight, T> {
         Self::to_custom(self)
     }
 
-    /// Converts a `Tight` sector to a `Fixed` sector.
+    /// Converts a `Tight` sector to a `Fixed` sector, keeping its current capacity.
+    ///
+    /// Use [`try_to_fixed`](Self::try_to_fixed) to reject sectors without capacity, or
+    /// [`to_fixed_with_capacity`](Self::to_fixed_with_capacity) to choose the capacity.
     pub fn to_fixed(self) -> Sector<super::Fixed, T> {
-        Self::to_custom(self)
+        let cap = self.fixed_capacity();
+        self.resize_to_fixed(cap)
+    }
+
+    /// Converts a `Tight` sector to a `Fixed` sector, keeping its current capacity.
+    ///
+    /// Fails and returns the sector unchanged if its capacity is `0`, as the resulting `Fixed`
+    /// sector could never hold an element.
+    pub fn try_to_fixed(self) -> Result<Sector<super::Fixed, T>, Self> {
+        self.try_resize_to_fixed()
+    }
+
+    /// Converts a `Tight` sector to a `Fixed` sector with a capacity of exactly `cap`, growing or
+    /// shrinking the allocation before the transition.
+    ///
+    /// Unlike the plain transitions this reallocates whenever `cap` differs from the current
+    /// capacity. Zero-sized types never allocate, for them only the enforced capacity is set.
+    ///
+    /// # Panics
+    ///
+    /// - if `cap` is smaller than the current length
+    /// - if the allocation fails
+    pub fn to_fixed_with_capacity(self, cap: usize) -> Sector<super::Fixed, T> {
+        self.resize_to_fixed(cap)
     }
 
     /// Converts a `Tight` sector to a `Locked` sector.
@@ -1,6 +1,8 @@
+use core::ptr::NonNull;
+
 use sector::{
-    states::{Manual, Normal},
-    Sector,
+    states::{Dynamic, Fixed, Locked, Manual, Normal, Tight},
+    CollectInto, Entry, Sector,
 };
 #[test]
 fn test_len() {
@@ -74,3 +76,1375 @@ fn test_creation() {
     let sec4 = Sector::<Normal, u32>::try_with_capacity(usize::MAX);
     assert!(sec4.is_err())
 }
+
+#[test]
+fn test_with_capacity_zero_does_not_allocate() {
+    let new = Sector::<Normal, u64>::new();
+    let zero = Sector::<Normal, u64>::with_capacity(0);
+    let try_zero = Sector::<Normal, u64>::try_with_capacity(0).unwrap();
+
+    assert_eq!(zero.capacity(), 0);
+    assert_eq!(try_zero.capacity(), 0);
+
+    // A dangling pointer is never handed out by the allocator
+    unsafe {
+        assert_eq!(zero.as_ptr(), NonNull::dangling());
+        assert_eq!(zero.as_ptr(), new.as_ptr());
+        assert_eq!(try_zero.as_ptr(), new.as_ptr());
+    }
+}
+
+#[test]
+fn test_count() {
+    let mut sec = Sector::<Normal, i32>::new();
+    for i in 0..10 {
+        sec.push(i);
+    }
+
+    assert_eq!(sec.count(|x| x % 2 == 0), 5);
+    assert_eq!(sec.count(|x| *x > 100), 0);
+}
+
+#[test]
+fn test_count_empty() {
+    let sec = Sector::<Normal, i32>::new();
+
+    assert_eq!(sec.count(|_| true), 0);
+}
+
+#[test]
+fn test_all_any() {
+    let mut sec = Sector::<Normal, i32>::new();
+    for i in 0..10 {
+        sec.push(i);
+    }
+
+    assert!(sec.all(|x| *x < 10));
+    assert!(sec.any(|x| *x == 9));
+
+    sec.push(11);
+    assert!(!sec.all(|x| *x < 10));
+    assert!(!sec.any(|x| *x > 11));
+}
+
+#[test]
+fn test_all_any_empty() {
+    let sec = Sector::<Normal, i32>::new();
+
+    assert!(sec.all(|_| false));
+    assert!(!sec.any(|_| true));
+}
+
+#[test]
+fn test_fold_ref_checksum() {
+    let sec: Sector<Normal, u8> = Sector::from([0x0f, 0xf0, 0x33, 0x01]);
+
+    let checksum = sec.fold_ref(0u8, |acc, byte| acc ^ byte);
+    assert_eq!(checksum, 0x0f ^ 0xf0 ^ 0x33 ^ 0x01);
+    assert_eq!(sec.len(), 4);
+
+    let empty: Sector<Normal, u8> = Sector::new();
+    assert_eq!(empty.fold_ref(0xaa, |acc, byte| acc ^ byte), 0xaa);
+}
+
+#[test]
+fn test_downsample_sum() {
+    let sec: Sector<Normal, i32> = Sector::from([1, 2, 3, 4, 5]);
+
+    let sums = sec.downsample_sum(2);
+    assert_eq!(sums, [3, 7, 5][..]);
+    assert_eq!(sums.capacity(), 3);
+
+    assert_eq!(sec.downsample_sum(5), [15][..]);
+    assert_eq!(sec.downsample_sum(1), sec);
+    assert!(Sector::<Normal, f64>::new().downsample_sum(3).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "downsample factor must be non-zero")]
+fn test_downsample_sum_zero_factor() {
+    let sec: Sector<Normal, i32> = Sector::from([1, 2]);
+    sec.downsample_sum(0);
+}
+
+#[test]
+fn test_try_for_each() {
+    let sec: Sector<Normal, i32> = Sector::from([1, 2, 3]);
+    assert_eq!(
+        sec.try_for_each(|x| if *x > 0 { Ok(()) } else { Err(*x) }),
+        Ok(())
+    );
+
+    let sec: Sector<Normal, i32> = Sector::from([1, -2, 3, -4]);
+    let mut visited = 0;
+    let result = sec.try_for_each(|x| {
+        visited += 1;
+        if *x > 0 {
+            Ok(())
+        } else {
+            Err(*x)
+        }
+    });
+    assert_eq!(result, Err(-2));
+    assert_eq!(visited, 2);
+}
+
+#[test]
+fn test_collect_into_dynamic() {
+    let sec = (0..100).collect_into_sector::<Dynamic>();
+    assert_eq!(&*sec, &(0..100).collect::<Vec<_>>()[..]);
+    assert_eq!(sec.capacity(), 100);
+
+    // Without a size hint the sector grows like it would when pushing
+    let sec = (0..100).filter(|_| true).collect_into_sector::<Dynamic>();
+    assert_eq!(sec.len(), 100);
+    assert_eq!(sec.capacity(), 128);
+}
+
+#[test]
+fn test_collect_into_tight() {
+    let sec = (0..100).collect_into_sector::<Tight>();
+    assert_eq!(&*sec, &(0..100).collect::<Vec<_>>()[..]);
+    assert_eq!(sec.capacity(), 100);
+
+    let sec = (0..100).filter(|_| true).collect_into_sector::<Tight>();
+    assert_eq!(sec.len(), 100);
+    assert_eq!(sec.capacity(), 100);
+}
+
+#[test]
+fn test_collect_into_empty() {
+    let sec = core::iter::empty::<i32>().collect_into_sector::<Normal>();
+    assert!(sec.is_empty());
+    assert_eq!(sec.capacity(), 0);
+}
+
+#[test]
+fn test_approx_eq() {
+    let sec: Sector<Normal, f64> = Sector::from([0.1 + 0.2, 1.0, -2.5]);
+    assert!(sec.approx_eq(&[0.3, 1.0, -2.5], 1e-9));
+    assert!(!sec.approx_eq(&[0.3, 1.1, -2.5], 1e-9));
+    assert!(!sec.approx_eq(&[0.3, 1.0], 1e-9));
+    assert!(!sec.approx_eq(&[0.3, 1.0, f64::NAN], 1.0));
+
+    let inf: Sector<Normal, f64> = Sector::from([f64::INFINITY, f64::NEG_INFINITY, 1.0]);
+    assert!(inf.approx_eq(&inf, 1e-9));
+    assert!(!inf.approx_eq(&[f64::INFINITY, f64::INFINITY, 1.0], 1e-9));
+    assert!(!inf.approx_eq(&[f64::MAX, f64::NEG_INFINITY, 1.0], 1e300));
+
+    let sec: Sector<Fixed, f32> = Sector::from([1.0, 2.0]);
+    assert!(sec.approx_eq(&[1.001, 1.999], 0.01));
+    assert!(!sec.approx_eq(&[1.0, 3.0], 0.01));
+    assert!(Sector::<Normal, f32>::from([f32::INFINITY]).approx_eq(&[f32::INFINITY], 0.0));
+
+    assert!(Sector::<Normal, f64>::new().approx_eq(&[], 0.0));
+}
+
+#[test]
+fn test_hash_bytes() {
+    use std::hash::{BuildHasher, Hasher, RandomState};
+
+    let build = RandomState::new();
+    let bytes: &[u8] = b"hello sector";
+    let sec = bytes.iter().copied().collect_into_sector::<Normal>();
+
+    let mut sector_hasher = build.build_hasher();
+    sec.hash_bytes(&mut sector_hasher);
+
+    assert_eq!(sector_hasher.finish(), build.hash_one(bytes));
+}
+
+#[test]
+fn test_hash_matches_slice() {
+    use std::{
+        collections::HashSet,
+        hash::{BuildHasher, RandomState},
+    };
+
+    let build = RandomState::new();
+    let mut normal: Sector<Normal, i32> = Sector::with_capacity(16);
+    normal.extend([1, 2, 3]);
+    let tight: Sector<Tight, i32> = Sector::from([1, 2, 3]);
+
+    assert_eq!(build.hash_one(&normal), build.hash_one(&tight));
+    assert_eq!(
+        build.hash_one(&normal),
+        build.hash_one([1, 2, 3].as_slice())
+    );
+
+    let mut set = HashSet::new();
+    assert!(set.insert(normal));
+    assert!(!set.insert(Sector::from([1, 2, 3])));
+    assert!(set.insert(Sector::from([3, 2, 1])));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_pairwise_deltas() {
+    let sec = [1, 3, 6, 10].into_iter().collect_into_sector::<Normal>();
+
+    let deltas: Vec<i32> = sec.pairwise().map(|(a, b)| b - a).collect();
+    assert_eq!(deltas, [2, 3, 4]);
+
+    let deltas: Vec<i32> = sec.windows(2).map(|w| w[1] - w[0]).collect();
+    assert_eq!(deltas, [2, 3, 4]);
+}
+
+#[test]
+fn test_pairwise_short() {
+    let mut sec = Sector::<Normal, i32>::new();
+    assert_eq!(sec.pairwise().count(), 0);
+
+    sec.push(1);
+    assert_eq!(sec.pairwise().count(), 0);
+    assert_eq!(sec.windows(2).count(), 0);
+}
+
+#[test]
+fn test_extend_from_refs() {
+    let mut sec = Sector::<Normal, i32>::new();
+    sec.push(0);
+
+    sec.extend([1, 2, 3].iter());
+    assert_eq!(&*sec, &[0, 1, 2, 3]);
+
+    let mut tight = Sector::<Tight, i32>::new();
+    tight.extend(&[4, 5]);
+    assert_eq!(&*tight, &[4, 5]);
+    assert_eq!(tight.capacity(), 2);
+}
+
+#[test]
+fn test_drain_all() {
+    let mut sec = Sector::<Normal, String>::new();
+    sec.push("a".to_string());
+    sec.push("b".to_string());
+    let cap = sec.capacity();
+
+    let drained: Vec<String> = sec.drain_all().collect();
+    assert_eq!(drained, ["a", "b"]);
+    assert!(sec.is_empty());
+    assert_eq!(sec.capacity(), cap);
+}
+
+#[test]
+fn test_flatten_in_place_reuses_first() {
+    let mut first = Sector::<Normal, String>::with_capacity(8);
+    first.push("a".to_string());
+    let first_ptr = unsafe { first.as_ptr() };
+
+    let mut second = Sector::<Normal, String>::new();
+    second.push("b".to_string());
+    second.push("c".to_string());
+
+    let mut outer = Sector::<Normal, Sector<Normal, String>>::new();
+    outer.push(first);
+    outer.push(Sector::new());
+    outer.push(second);
+
+    let flat = outer.flatten_in_place();
+    assert_eq!(&*flat, &["a", "b", "c"]);
+    assert_eq!(unsafe { flat.as_ptr() }, first_ptr);
+    assert_eq!(flat.capacity(), 8);
+}
+
+#[test]
+fn test_flatten_in_place_fresh_allocation() {
+    let mut outer = Sector::<Normal, Sector<Tight, i32>>::new();
+    for chunk in [[1, 2], [3, 4], [5, 6]] {
+        outer.push(chunk.iter().copied().collect_into_sector::<Tight>());
+    }
+
+    let flat = outer.flatten_in_place();
+    assert_eq!(&*flat, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(flat.capacity(), 6);
+
+    let empty = Sector::<Normal, Sector<Normal, i32>>::new();
+    assert!(empty.flatten_in_place().is_empty());
+}
+
+#[test]
+fn test_to_dynamic_tight() {
+    let mut sec = Sector::<Normal, i32>::new();
+    for i in 0..100 {
+        sec.push(i);
+    }
+    for _ in 0..90 {
+        sec.pop();
+    }
+    assert_eq!(sec.capacity(), 128);
+
+    let dynamic = sec.to_dynamic_tight();
+    assert_eq!(&*dynamic, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(dynamic.capacity(), dynamic.len());
+}
+
+#[test]
+fn test_to_shrinking() {
+    let mut sec = Sector::<Normal, i32>::new();
+    for i in 0..16 {
+        sec.push(i);
+    }
+
+    let mut dynamic = sec.to_shrinking();
+    assert_eq!(dynamic.capacity(), 16);
+    for _ in 0..8 {
+        dynamic.pop();
+    }
+    assert!(dynamic.capacity() < 16);
+}
+
+#[test]
+fn test_enumerate_mut() {
+    let mut sec = Sector::<Normal, usize>::new();
+    for _ in 0..5 {
+        sec.push(42);
+    }
+
+    for (i, elem) in sec.enumerate_mut() {
+        *elem = i;
+    }
+    assert_eq!(&*sec, &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_into_sliding_chunks() {
+    let sec = [1, 2, 3, 4].into_iter().collect_into_sector::<Normal>();
+
+    let windows: Vec<Vec<i32>> = sec
+        .into_sliding_chunks(3, 1)
+        .map(|chunk| chunk.to_vec())
+        .collect();
+    assert_eq!(windows, [[1, 2, 3], [2, 3, 4]]);
+
+    let sec = [1, 2, 3, 4, 5].into_iter().collect_into_sector::<Normal>();
+    let windows: Vec<Vec<i32>> = sec
+        .into_sliding_chunks(2, 2)
+        .map(|chunk| chunk.to_vec())
+        .collect();
+    assert_eq!(windows, [[1, 2], [3, 4]]);
+}
+
+#[test]
+#[should_panic(expected = "step must be non-zero")]
+fn test_into_sliding_chunks_zero_step() {
+    let sec = [1, 2, 3].into_iter().collect_into_sector::<Normal>();
+    let _ = sec.into_sliding_chunks(2, 0);
+}
+
+#[test]
+fn test_clone() {
+    let mut sec = Sector::<Dynamic, String>::with_capacity(10);
+    sec.push("a".to_string());
+    sec.push("b".to_string());
+
+    let clone = sec.clone();
+    assert_eq!(&*clone, &*sec);
+    assert_eq!(clone.capacity(), 2);
+    assert_ne!(unsafe { clone.as_ptr() }, unsafe { sec.as_ptr() });
+}
+
+#[test]
+fn test_clone_zst() {
+    let mut sec = Sector::<Normal, ()>::new();
+    for _ in 0..3 {
+        sec.push(());
+    }
+
+    let clone = sec.clone();
+    assert_eq!(clone.len(), 3);
+    assert_eq!(clone.capacity(), usize::MAX);
+}
+
+#[test]
+fn test_clone_panic_drops_cloned() {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        rc::Rc,
+    };
+
+    struct PanicOnThirdClone(Rc<()>, usize);
+
+    impl Clone for PanicOnThirdClone {
+        fn clone(&self) -> Self {
+            assert!(self.1 != 2, "clone failed");
+            PanicOnThirdClone(Rc::clone(&self.0), self.1)
+        }
+    }
+
+    let shared = Rc::new(());
+    let mut sec = Sector::<Normal, PanicOnThirdClone>::new();
+    for i in 0..4 {
+        sec.push(PanicOnThirdClone(Rc::clone(&shared), i));
+    }
+    assert_eq!(Rc::strong_count(&shared), 5);
+
+    let result = catch_unwind(AssertUnwindSafe(|| sec.clone()));
+    assert!(result.is_err());
+    // The two finished clones were dropped again
+    assert_eq!(Rc::strong_count(&shared), 5);
+}
+
+#[test]
+fn test_debug() {
+    let sec = [1, 2, 3].into_iter().collect_into_sector::<Normal>();
+    assert_eq!(format!("{sec:?}"), "Sector<Normal> [1, 2, 3]");
+
+    let sec = Sector::<Manual, i32>::new();
+    assert_eq!(format!("{sec:?}"), "Sector<Manual> []");
+
+    let sec = ["a"].into_iter().collect_into_sector::<Tight>();
+    assert_eq!(format!("{sec:#?}"), "Sector<Tight> [\n    \"a\",\n]");
+}
+
+#[test]
+fn test_eq() {
+    let normal = [1, 2, 3].into_iter().collect_into_sector::<Normal>();
+    let tight = [1, 2, 3].into_iter().collect_into_sector::<Tight>();
+    let mut dynamic = Sector::<Dynamic, i32>::with_capacity(10);
+    dynamic.extend(&[1, 2]);
+
+    assert_eq!(normal, tight);
+    assert_ne!(normal, dynamic);
+    dynamic.push(3);
+    assert_eq!(normal, dynamic);
+
+    assert_eq!(normal, vec![1, 2, 3][..]);
+    assert_eq!(normal, &[1, 2, 3][..]);
+    assert_ne!(normal, &[1, 2][..]);
+}
+
+#[test]
+fn test_eq_ignores_spare_capacity() {
+    let mut a = Sector::<Normal, i32>::with_capacity(8);
+    let mut b = Sector::<Normal, i32>::with_capacity(2);
+    a.push(1);
+    b.push(1);
+
+    assert_eq!(a, b);
+    assert_eq!(Sector::<Normal, i32>::new(), Sector::<Tight, i32>::new());
+}
+
+#[test]
+fn test_split_once() {
+    let sec = b"key=v".iter().copied().collect_into_sector::<Normal>();
+
+    assert_eq!(sec.split_once(&b'='), Some((&b"key"[..], &b"v"[..])));
+    assert_eq!(sec.split_once(&b':'), None);
+}
+
+#[test]
+fn test_rsplit_once() {
+    let sec = b"a=b=c".iter().copied().collect_into_sector::<Normal>();
+
+    assert_eq!(sec.split_once(&b'='), Some((&b"a"[..], &b"b=c"[..])));
+    assert_eq!(sec.rsplit_once(&b'='), Some((&b"a=b"[..], &b"c"[..])));
+    assert_eq!(sec.rsplit_once(&b'c'), Some((&b"a=b="[..], &b""[..])));
+}
+
+/// Builds a sector of the given state with a capacity of 10 and the elements `[1, 2, 3]`.
+fn transition_source<State>() -> Sector<State, i32> {
+    let mut sec = Sector::<Normal, i32>::with_capacity(10);
+    sec.extend_from_slice(&[1, 2, 3]);
+    sec.to_custom()
+}
+
+macro_rules! assert_transitions {
+    ($from:ty => $($method:ident),+) => {
+        $(
+            let sec = transition_source::<$from>();
+            let ptr = unsafe { sec.as_ptr() };
+            let converted = sec.$method();
+            assert_eq!(converted.capacity(), 10, "{} {}", stringify!($from), stringify!($method));
+            assert_eq!(converted.len(), 3, "{} {}", stringify!($from), stringify!($method));
+            assert_eq!(&*converted, &[1, 2, 3]);
+            assert_eq!(unsafe { converted.as_ptr() }, ptr);
+        )+
+    };
+}
+
+#[test]
+fn test_transitions_preserve_buffer() {
+    assert_transitions!(Normal => to_dynamic, to_fixed, to_tight, to_locked, to_manual);
+    assert_transitions!(Dynamic => to_normal, to_fixed, to_tight, to_locked, to_manual);
+    assert_transitions!(Fixed => to_normal, to_dynamic, to_tight, to_locked, to_manual);
+    assert_transitions!(Tight => to_normal, to_dynamic, to_fixed, to_locked, to_manual);
+    assert_transitions!(Locked => to_normal, to_dynamic, to_fixed, to_tight, to_manual);
+    assert_transitions!(Manual => to_normal, to_dynamic, to_fixed, to_tight, to_locked);
+}
+
+#[test]
+fn test_from_iterator() {
+    let sec: Sector<Normal, i32> = (0..10).collect();
+    assert_eq!(sec, (0..10).collect::<Vec<_>>()[..]);
+    assert_eq!(sec.capacity(), 10);
+
+    let sec: Sector<Dynamic, i32> = (0..10).collect();
+    assert_eq!(sec.len(), 10);
+}
+
+#[test]
+fn test_extend_beyond_size_hint() {
+    /// Reports a lower bound of 2, but yields more elements
+    struct Understated(std::ops::Range<i32>);
+
+    impl Iterator for Understated {
+        type Item = i32;
+
+        fn next(&mut self) -> Option<i32> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (2, None)
+        }
+    }
+
+    let mut sec = Sector::<Dynamic, i32>::new();
+    sec.push(-1);
+    sec.extend(Understated(0..20));
+    assert_eq!(sec.len(), 21);
+    assert_eq!(sec[0], -1);
+    assert_eq!(sec[20], 19);
+
+    let mut sec = Sector::<Normal, String>::new();
+    sec.extend(["a".to_string(), "b".to_string()]);
+    assert_eq!(sec, ["a", "b"][..]);
+}
+
+#[test]
+fn test_stride() {
+    let sec: Sector<Normal, i32> = (0..6).collect();
+
+    assert_eq!(sec.stride(2).copied().collect::<Vec<_>>(), [0, 2, 4]);
+    assert_eq!(sec.stride(4).copied().collect::<Vec<_>>(), [0, 4]);
+    assert_eq!(sec.stride(1).count(), 6);
+}
+
+#[test]
+#[should_panic]
+fn test_stride_zero() {
+    let sec: Sector<Normal, i32> = (0..6).collect();
+    let _ = sec.stride(0);
+}
+
+#[test]
+fn test_try_fold_into() {
+    let sec: Sector<Normal, i32> = (1..=4).collect();
+    assert_eq!(sec.try_fold_into(0, |acc, x| Ok::<_, ()>(acc + x)), Ok(10));
+}
+
+#[test]
+fn test_try_fold_into_short_circuits() {
+    use std::rc::Rc;
+
+    let shared = Rc::new(());
+    let sec: Sector<Normal, (i32, Rc<()>)> = [1, 2, -1, 3, 4]
+        .into_iter()
+        .map(|x| (x, Rc::clone(&shared)))
+        .collect();
+
+    let mut visited = 0;
+    let result = sec.try_fold_into(0, |acc, (x, _)| {
+        visited += 1;
+        if x < 0 {
+            Err(acc)
+        } else {
+            Ok(acc + x)
+        }
+    });
+
+    assert_eq!(result, Err(3));
+    assert_eq!(visited, 3);
+    // The unvisited elements were dropped as well
+    assert_eq!(Rc::strong_count(&shared), 1);
+}
+
+#[test]
+fn test_retain_panic_is_consistent() {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        rc::Rc,
+    };
+
+    let shared = Rc::new(());
+    let mut sec: Sector<Normal, (i32, Rc<()>)> = (0..6).map(|x| (x, Rc::clone(&shared))).collect();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        sec.retain(|(x, _)| {
+            assert!(*x != 3, "predicate failed");
+            x % 2 == 0
+        })
+    }));
+    assert!(result.is_err());
+
+    // 1 was removed, 3 panicked and stays, everything else is untouched
+    let remaining: Vec<i32> = sec.iter().map(|(x, _)| *x).collect();
+    assert_eq!(remaining, [0, 2, 3, 4, 5]);
+    assert_eq!(Rc::strong_count(&shared), 6);
+
+    drop(sec);
+    assert_eq!(Rc::strong_count(&shared), 1);
+}
+
+/// Counts its drops and panics when dropped with `panic` set
+struct PanicOnDrop<'a> {
+    drops: &'a std::cell::Cell<usize>,
+    panic: bool,
+}
+
+impl Drop for PanicOnDrop<'_> {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+        if self.panic {
+            panic!("drop failed");
+        }
+    }
+}
+
+#[test]
+fn test_drain_drop_panic_drops_rest() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let drops = std::cell::Cell::new(0);
+    let mut sec: Sector<Normal, PanicOnDrop> = (0..5)
+        .map(|i| PanicOnDrop {
+            drops: &drops,
+            panic: i == 2,
+        })
+        .collect();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let mut drain = sec.drain();
+        drop(drain.next());
+    }));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 5);
+    assert!(sec.is_empty());
+}
+
+#[test]
+fn test_into_iter_drop_panic_drops_rest() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let drops = std::cell::Cell::new(0);
+    let sec: Sector<Normal, PanicOnDrop> = (0..5)
+        .map(|i| PanicOnDrop {
+            drops: &drops,
+            panic: i == 1,
+        })
+        .collect();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let mut iter = sec.into_iter();
+        drop(iter.next_back());
+    }));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn test_into_iter_zst_size_hint() {
+    let sec: Sector<Normal, ()> = std::iter::repeat_n((), 4).collect();
+
+    let mut iter = sec.into_iter();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn test_locked_iter() {
+    let sec: Sector<Normal, i32> = (1..=3).collect();
+    let locked = sec.to_locked();
+
+    assert_eq!(locked.iter().sum::<i32>(), 6);
+}
+
+#[test]
+fn test_from_str() {
+    let sec = Sector::from("hello");
+    assert_eq!(sec, b"hello"[..]);
+    assert_eq!(sec.capacity(), 5);
+
+    assert!(Sector::from("").is_empty());
+}
+
+#[test]
+fn test_from_string_moves_allocation() {
+    let mut s = String::with_capacity(16);
+    s.push_str("hello");
+    let ptr = s.as_ptr();
+
+    let mut sec = Sector::from(s);
+    assert_eq!(sec, b"hello"[..]);
+    assert_eq!(sec.capacity(), 16);
+    assert_eq!(unsafe { sec.as_ptr() }.as_ptr() as *const u8, ptr);
+
+    sec.push(b'!');
+    assert_eq!(sec, b"hello!"[..]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_join() {
+    let sec: Sector<Normal, String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+
+    let joined = sec.join(", ");
+    assert_eq!(joined, "a, b, c");
+    assert_eq!(joined.capacity(), joined.len());
+
+    assert_eq!(Sector::<Normal, String>::new().join(", "), "");
+}
+
+#[test]
+fn test_run_length_encode() {
+    let sec: Sector<Normal, char> = "aaabcc".chars().collect();
+
+    let runs = sec.run_length_encode();
+    assert_eq!(runs, [('a', 3), ('b', 1), ('c', 2)][..]);
+
+    assert!(Sector::<Normal, char>::new().run_length_encode().is_empty());
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_realloc_hook() {
+    use sector::metrics;
+    use std::{cell::RefCell, rc::Rc};
+
+    let recorded = Rc::new(RefCell::new(Vec::new()));
+    let hook_recorded = Rc::clone(&recorded);
+    metrics::set_realloc_hook(move |old, new| hook_recorded.borrow_mut().push((old, new)));
+
+    let mut sec = Sector::<Normal, i32>::new();
+    for i in 0..9 {
+        sec.push(i);
+    }
+    let mut tight = Sector::<Tight, i32>::new();
+    tight.extend_from_slice(&[1, 2, 3]);
+    tight.truncate(1);
+
+    metrics::clear_realloc_hook();
+    sec.push(9);
+
+    assert_eq!(
+        *recorded.borrow(),
+        [(0, 1), (1, 2), (2, 4), (4, 8), (8, 16), (0, 3), (3, 1)]
+    );
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_realloc_hook_panic_keeps_sector_consistent() {
+    use sector::metrics;
+    use std::panic::{self, AssertUnwindSafe};
+
+    let mut sec = (0..64u64).collect_into_sector::<Dynamic>();
+    assert_eq!(sec.capacity(), 64);
+    for _ in 0..31 {
+        sec.pop();
+    }
+
+    metrics::set_realloc_hook(|_, _| panic!("hook panicked"));
+    // Dropping below half of the capacity shrinks the buffer, which panics in the hook
+    let result = panic::catch_unwind(AssertUnwindSafe(|| sec.pop()));
+    metrics::clear_realloc_hook();
+    assert!(result.is_err());
+
+    // The new buffer was recorded before the hook ran
+    assert_eq!(sec.len(), 32);
+    assert_eq!(sec.capacity(), 48);
+    assert!(sec.iter().copied().eq(0..32));
+
+    sec.extend(32..100);
+    assert!(sec.iter().copied().eq(0..100));
+}
+
+#[test]
+fn test_map_lanes() {
+    let mut sec: Sector<Normal, i32> = (0..8).collect();
+
+    let (lanes, rest) = sec.as_chunks_mut::<4>();
+    assert_eq!(lanes.len(), 2);
+    assert!(rest.is_empty());
+
+    sec.map_lanes::<4, _>(|lane| lane.iter_mut().for_each(|x| *x += 1));
+    assert_eq!(sec, (1..9).collect::<Vec<_>>()[..]);
+}
+
+#[test]
+fn test_map_lanes_skips_remainder() {
+    let mut sec: Sector<Normal, i32> = (0..6).collect();
+
+    sec.map_lanes::<4, _>(|lane| *lane = [0; 4]);
+    assert_eq!(sec, [0, 0, 0, 0, 4, 5][..]);
+    assert_eq!(sec.as_chunks_mut::<4>().1, &mut [4, 5]);
+}
+
+#[test]
+fn test_unzip_into() {
+    let sec: Sector<Normal, (i32, char)> = [(1, 'a'), (2, 'b')].into_iter().collect();
+
+    let (numbers, chars) = sec.unzip_into();
+    assert_eq!(numbers, [1, 2][..]);
+    assert_eq!(chars, ['a', 'b'][..]);
+    assert_eq!(numbers.capacity(), 2);
+    assert_eq!(chars.capacity(), 2);
+}
+
+#[test]
+fn test_unzip_into_drops() {
+    use std::rc::Rc;
+
+    let shared = Rc::new(());
+    let sec: Sector<Normal, (Rc<()>, Rc<()>)> = (0..3)
+        .map(|_| (Rc::clone(&shared), Rc::clone(&shared)))
+        .collect();
+    assert_eq!(Rc::strong_count(&shared), 7);
+
+    let (left, right) = sec.unzip_into();
+    assert_eq!(Rc::strong_count(&shared), 7);
+
+    drop(left);
+    assert_eq!(Rc::strong_count(&shared), 4);
+    drop(right);
+    assert_eq!(Rc::strong_count(&shared), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_from_vec_and_into_vec() {
+    let mut vec = Vec::with_capacity(10);
+    vec.extend(["a".to_string(), "b".to_string()]);
+    let ptr = vec.as_ptr();
+
+    let mut sec: Sector<Dynamic, String> = Sector::from(vec);
+    assert_eq!(sec, ["a", "b"][..]);
+    assert_eq!(sec.capacity(), 10);
+    assert_eq!(unsafe { sec.as_ptr() }.as_ptr() as *const String, ptr);
+
+    sec.push("c".to_string());
+    let vec = sec.into_vec();
+    assert_eq!(vec, ["a", "b", "c"]);
+    assert_eq!(vec.capacity(), 10);
+    assert_eq!(vec.as_ptr(), ptr);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vec_conversion_empty_and_zst() {
+    let sec: Sector<Normal, i32> = Sector::from(Vec::new());
+    assert_eq!(sec.capacity(), 0);
+    assert!(sec.into_vec().is_empty());
+
+    let sec: Sector<Normal, ()> = Sector::from(vec![(); 3]);
+    assert_eq!(sec.len(), 3);
+    assert_eq!(sec.into_vec().len(), 3);
+}
+
+#[test]
+fn test_locked_compact() {
+    let mut sec = Sector::<Normal, i32>::with_capacity(16);
+    sec.push(1);
+
+    let mut locked = sec.to_locked();
+    locked.compact();
+    assert_eq!(locked.capacity(), 16);
+}
+
+#[test]
+fn test_from_array() {
+    let sec: Sector<Fixed, String> = Sector::from(["a".to_string(), "b".to_string()]);
+    assert_eq!(sec, ["a", "b"][..]);
+    assert_eq!(sec.capacity(), sec.len());
+
+    let empty: Sector<Normal, i32> = Sector::from([]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.capacity(), 0);
+
+    let zst: Sector<Normal, ()> = Sector::from([(); 4]);
+    assert_eq!(zst.len(), 4);
+}
+
+#[test]
+fn test_locked_as_slice() {
+    let locked = Sector::<Normal, i32>::from([1, 2]).to_locked();
+    assert_eq!(locked.as_slice(), &[1, 2]);
+}
+
+#[test]
+fn test_try_to_fixed() {
+    let tight: Sector<Tight, i32> = Sector::new();
+    let tight = tight.try_to_fixed().unwrap_err();
+    assert_eq!(tight.capacity(), 0);
+
+    let mut normal: Sector<Normal, i32> = Sector::new();
+    normal.push(1);
+    let cap = normal.capacity();
+    let fixed = normal.try_to_fixed().ok().unwrap();
+    assert_eq!(fixed.capacity(), cap);
+    assert_eq!(&*fixed, &[1]);
+}
+
+#[test]
+fn test_to_fixed_with_capacity() {
+    let tight: Sector<Tight, i32> = Sector::from([1, 2]);
+    let mut fixed = tight.to_fixed_with_capacity(4);
+    assert_eq!(fixed.capacity(), 4);
+    assert_eq!(fixed.push(3), Ok(()));
+    assert_eq!(&*fixed, &[1, 2, 3]);
+
+    let mut normal: Sector<Normal, i32> = Sector::with_capacity(10);
+    normal.push(1);
+    let fixed = normal.to_fixed_with_capacity(1);
+    assert_eq!(fixed.capacity(), 1);
+    assert_eq!(&*fixed, &[1]);
+
+    let manual: Sector<Manual, ()> = Sector::from([(), ()]);
+    assert_eq!(manual.to_fixed_with_capacity(5).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "fixed capacity (is 1) should be >= len (is 2)")]
+fn test_to_fixed_with_capacity_below_len() {
+    let normal: Sector<Normal, i32> = Sector::from([1, 2]);
+    let _ = normal.to_fixed_with_capacity(1);
+}
+
+#[test]
+fn test_locked_unlock() {
+    let locked = Sector::<Normal, i32>::from([1, 2]).to_locked();
+    let mut normal = locked.unlock();
+    normal.push(3);
+    assert_eq!(&*normal, &[1, 2, 3]);
+}
+
+#[test]
+fn test_locked_with_unlocked() {
+    let locked = Sector::<Normal, i32>::from([1, 2]).to_locked();
+
+    let (locked, popped) = locked.with_unlocked(|sector| {
+        sector.push(3);
+        sector.pop()
+    });
+    assert_eq!(popped, Some(3));
+
+    let (locked, len) = locked.with_unlocked(|sector| {
+        sector.push(4);
+        sector.len()
+    });
+    assert_eq!(len, 3);
+    assert_eq!(locked.as_slice(), &[1, 2, 4]);
+}
+
+#[test]
+fn test_over_aligned_elements() {
+    #[repr(align(64))]
+    #[derive(Debug, PartialEq)]
+    struct Cacheline(u8);
+
+    fn assert_aligned<State>(sector: &Sector<State, Cacheline>) {
+        let addr = unsafe { sector.as_ptr() }.as_ptr() as usize;
+        assert_eq!(addr % core::mem::align_of::<Cacheline>(), 0);
+    }
+
+    let mut sector: Sector<Normal, Cacheline> = Sector::new();
+    for i in 0..100 {
+        sector.push(Cacheline(i));
+        assert_aligned(&sector);
+    }
+
+    let mut sector = sector.to_dynamic();
+    while sector.len() > 1 {
+        sector.pop();
+        assert_aligned(&sector);
+    }
+    assert_eq!(sector.as_slice(), &[Cacheline(0)]);
+
+    let sector: Sector<Tight, Cacheline> = Sector::with_capacity(3);
+    assert_aligned(&sector);
+}
+
+#[test]
+fn test_zst_capacity_after_transition() {
+    let mut normal: Sector<Normal, ()> = Sector::with_capacity(2);
+    for _ in 0..4 {
+        normal.push(());
+    }
+
+    // The length exceeds the requested capacity, so the sector is simply full
+    let mut manual = normal.to_manual();
+    assert_eq!(manual.push(()), Err(()));
+    assert_eq!(manual.len(), 4);
+
+    let mut fixed = manual.to_normal().to_fixed_with_capacity(6);
+    assert_eq!(fixed.push(()), Ok(()));
+    assert_eq!(fixed.push(()), Ok(()));
+    assert_eq!(fixed.push(()), Err(()));
+}
+
+#[test]
+fn test_zst_capacity_kept_by_to_fixed() {
+    let mut fixed = Sector::<Manual, ()>::with_capacity(2).to_fixed();
+    assert_eq!(fixed.push(()), Ok(()));
+    assert_eq!(fixed.push(()), Ok(()));
+    assert_eq!(fixed.push(()), Err(()));
+
+    let mut fixed = Sector::<Fixed, ()>::with_capacity(2).to_normal().to_fixed();
+    assert_eq!(fixed.push(()), Ok(()));
+    assert_eq!(fixed.push(()), Ok(()));
+    assert_eq!(fixed.push(()), Err(()));
+
+    let mut fixed = Sector::<Normal, ()>::new().to_fixed();
+    for _ in 0..10 {
+        assert_eq!(fixed.push(()), Ok(()));
+    }
+}
+
+#[test]
+fn test_locked_contains_and_binary_search() {
+    let locked = Sector::<Normal, i32>::from([2, 4, 6]).to_locked();
+
+    assert!(locked.contains(&4));
+    assert!(!locked.contains(&5));
+    assert_eq!(locked.binary_search(&6), Ok(2));
+    assert_eq!(locked.binary_search(&5), Err(2));
+}
+
+#[test]
+fn test_locked_partition_point() {
+    let locked = Sector::<Normal, i32>::from([1, 2, 3, 7, 8]).to_locked();
+    assert_eq!(locked.partition_point(|x| *x < 5), 3);
+}
+
+/// Records its id into a shared log when dropped, to check the order of drops.
+struct DropLogger<'a> {
+    id: usize,
+    log: &'a std::cell::RefCell<Vec<usize>>,
+}
+
+impl Drop for DropLogger<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.id);
+    }
+}
+
+fn logged_sector(log: &std::cell::RefCell<Vec<usize>>) -> Sector<Normal, DropLogger<'_>> {
+    (0..6).map(|id| DropLogger { id, log }).collect()
+}
+
+#[test]
+fn test_drop_order_clear() {
+    let log = std::cell::RefCell::new(Vec::new());
+
+    let mut sector = logged_sector(&log);
+    sector.clear();
+    assert_eq!(*log.borrow(), [0, 1, 2, 3, 4, 5]);
+
+    log.borrow_mut().clear();
+    let mut fixed = logged_sector(&log).to_fixed();
+    fixed.clear();
+    assert_eq!(*log.borrow(), [0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_drop_order_truncate() {
+    let log = std::cell::RefCell::new(Vec::new());
+
+    let mut sector = logged_sector(&log);
+    sector.truncate(2);
+    assert_eq!(*log.borrow(), [2, 3, 4, 5]);
+
+    drop(sector);
+    assert_eq!(*log.borrow(), [2, 3, 4, 5, 0, 1]);
+
+    log.borrow_mut().clear();
+    let mut tight = logged_sector(&log).to_tight();
+    tight.truncate(3);
+    assert_eq!(*log.borrow(), [3, 4, 5]);
+}
+
+#[test]
+fn test_drop_order_drain() {
+    let log = std::cell::RefCell::new(Vec::new());
+
+    let mut sector = logged_sector(&log);
+    sector.drain().for_each(drop);
+    assert_eq!(*log.borrow(), [0, 1, 2, 3, 4, 5]);
+
+    log.borrow_mut().clear();
+    let mut sector = logged_sector(&log);
+    let mut drain = sector.drain();
+    drop(drain.next());
+    drop(drain);
+    assert_eq!(*log.borrow(), [0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_drop_order_retain() {
+    let log = std::cell::RefCell::new(Vec::new());
+
+    let mut sector = logged_sector(&log);
+    sector.retain(|elem| elem.id == 2);
+    assert_eq!(*log.borrow(), [0, 1, 3, 4, 5]);
+
+    log.borrow_mut().clear();
+    let mut manual = logged_sector(&log).to_manual();
+    assert_eq!(manual.retain(|elem| elem.id % 2 == 0), 3);
+    assert_eq!(*log.borrow(), [1, 3, 5]);
+}
+
+#[test]
+fn test_map_into_panic_drops_everything_once() {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        rc::Rc,
+    };
+
+    let shared = Rc::new(());
+    let sec: Sector<Normal, Rc<()>> = (0..6).map(|_| Rc::clone(&shared)).collect();
+
+    let mut calls = 0;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        sec.map_into(|elem| {
+            calls += 1;
+            assert!(calls != 3, "map failed");
+            elem
+        })
+    }));
+    assert!(result.is_err());
+
+    // Mapped, panicked and untouched elements were all dropped exactly once
+    assert_eq!(Rc::strong_count(&shared), 1);
+}
+
+#[test]
+fn test_drain_range_panic_still_moves_tail() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct PanicOnDrop(i32);
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            if self.0 == 2 && !std::thread::panicking() {
+                panic!("drop failed");
+            }
+        }
+    }
+
+    let mut sec: Sector<Normal, PanicOnDrop> = (0..6).map(PanicOnDrop).collect();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        sec.drain_range(1..4);
+    }));
+    assert!(result.is_err());
+
+    let remaining: Vec<i32> = sec.iter().map(|elem| elem.0).collect();
+    assert_eq!(remaining, [0, 4, 5]);
+}
+
+#[test]
+fn test_prepend_from_slice_panic_keeps_elements() {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        rc::Rc,
+    };
+
+    /// Panics when the element with id 1 gets cloned
+    struct PanicOnClone(i32, Rc<()>);
+
+    impl Clone for PanicOnClone {
+        fn clone(&self) -> Self {
+            assert!(self.0 != 1, "clone failed");
+            PanicOnClone(self.0, Rc::clone(&self.1))
+        }
+    }
+
+    let shared = Rc::new(());
+    let mut sec: Sector<Normal, PanicOnClone> = (10..13)
+        .map(|x| PanicOnClone(x, Rc::clone(&shared)))
+        .collect();
+    let items = [0, 1, 2].map(|x| PanicOnClone(x, Rc::clone(&shared)));
+
+    let result = catch_unwind(AssertUnwindSafe(|| sec.prepend_from_slice(&items)));
+    assert!(result.is_err());
+
+    // The clone of 0 made it in, the old elements were moved back behind it
+    let ids: Vec<i32> = sec.iter().map(|elem| elem.0).collect();
+    assert_eq!(ids, [0, 10, 11, 12]);
+    assert_eq!(Rc::strong_count(&shared), 1 + 3 + 4);
+
+    drop(sec);
+    drop(items);
+    assert_eq!(Rc::strong_count(&shared), 1);
+}
+
+#[test]
+fn test_into_iter_exact_size_and_fused() {
+    let sec: Sector<Normal, i32> = (0..5).collect();
+    let mut iter = sec.into_iter();
+    assert_eq!(iter.len(), 5);
+
+    iter.next();
+    iter.next_back();
+    assert_eq!(iter.len(), 3);
+
+    assert_eq!(iter.by_ref().count(), 3);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_drain_exact_size_and_fused() {
+    let mut sec: Sector<Dynamic, ()> = (0..4).map(|_| ()).collect();
+    let mut drain = sec.drain();
+    assert_eq!(drain.len(), 4);
+
+    drain.next();
+    assert_eq!(drain.len(), 3);
+
+    let mut fused = drain.fuse();
+    assert_eq!(fused.by_ref().count(), 3);
+    assert_eq!(fused.len(), 0);
+    assert_eq!(fused.next(), None);
+    assert_eq!(fused.next(), None);
+}
+
+#[test]
+fn test_locked_into_iter_by_reference() {
+    let locked = Sector::<Normal, i32>::from([1, 2, 3]).to_locked();
+
+    let mut collected = Vec::new();
+    for elem in &locked {
+        collected.push(*elem);
+    }
+    assert_eq!(collected, [1, 2, 3]);
+}
+
+#[test]
+fn test_default_in_derived_struct() {
+    #[derive(Default)]
+    struct Buffers {
+        normal: Sector<Normal, i32>,
+        fixed: Sector<Fixed, i32>,
+        locked: Sector<Locked, String>,
+    }
+
+    let buffers = Buffers::default();
+    assert!(buffers.normal.is_empty());
+    assert_eq!(buffers.fixed.capacity(), 0);
+    assert_eq!(buffers.locked.len(), 0);
+
+    let sectors: Vec<Sector<Dynamic, i32>> = Vec::new();
+    assert!(sectors.into_iter().next().unwrap_or_default().is_empty());
+}
+
+#[test]
+fn test_entry_occupied() {
+    let mut sec: Sector<Normal, i32> = Sector::from([1, 2, 3]);
+
+    *sec.entry(1).or_insert_with(|| unreachable!()) += 10;
+    assert_eq!(sec, [1, 12, 3].as_slice());
+    assert!(matches!(sec.entry(2), Entry::Occupied(&mut 3)));
+}
+
+#[test]
+fn test_entry_vacant() {
+    let mut sec: Sector<Dynamic, i32> = Sector::from([1]);
+
+    match sec.entry(3) {
+        Entry::Vacant(vacant) => assert_eq!(vacant.index(), 3),
+        Entry::Occupied(_) => panic!("index 3 should be vacant"),
+    }
+
+    let mut next = 10;
+    let elem = sec.entry(3).or_insert_with(|| {
+        next += 1;
+        next
+    });
+    assert_eq!(*elem, 13);
+    assert_eq!(sec, [1, 11, 12, 13].as_slice());
+
+    let mut tight: Sector<Tight, i32> = Sector::new();
+    *tight.entry(0).or_insert_with(|| 5) *= 2;
+    assert_eq!(tight, [10].as_slice());
+    assert_eq!(tight.capacity(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn test_entry_vacant_max_index() {
+    let mut sec: Sector<Normal, i32> = Sector::from([1]);
+    sec.entry(usize::MAX).or_insert_with(|| 0);
+}
+
+#[test]
+fn test_len_capacity_is_empty_on_every_state() {
+    fn check<State>(sector: &Sector<State, i32>, len: usize) {
+        assert_eq!(sector.len(), len);
+        assert_eq!(sector.is_empty(), len == 0);
+        assert!(sector.capacity() >= len);
+    }
+
+    let normal: Sector<Normal, i32> = Sector::from([1, 2]);
+    check(&normal, 2);
+    check(&Sector::<Dynamic, i32>::new(), 0);
+    check(&Sector::<Fixed, i32>::with_capacity(4), 0);
+    check(&Sector::<Tight, i32>::from([1]), 1);
+    check(&Sector::<Manual, i32>::new(), 0);
+    check(&normal.to_locked(), 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_cow_conversions() {
+    use std::borrow::Cow;
+
+    let sec: Sector<Tight, i32> = Sector::from([1, 2, 3]);
+    let cow = sec.to_cow();
+    assert!(matches!(cow, Cow::Borrowed(_)));
+    assert_eq!(&*cow, &[1, 2, 3]);
+
+    let borrowed = Sector::from_cow(Cow::Borrowed(&[4, 5][..]));
+    assert_eq!(borrowed, [4, 5][..]);
+
+    let vec = vec![6, 7, 8];
+    let ptr = vec.as_ptr();
+    let owned = Sector::from_cow(Cow::Owned(vec));
+    assert_eq!(owned, [6, 7, 8][..]);
+    assert_eq!(unsafe { owned.as_ptr() }.as_ptr() as *const i32, ptr);
+
+    let roundtrip = Sector::from_cow(owned.to_cow());
+    assert_eq!(roundtrip, owned);
+}
+
+#[test]
+fn test_retain_mut_panic_is_consistent() {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        rc::Rc,
+    };
+
+    let shared = Rc::new(());
+    let mut sec: Sector<Tight, (i32, Rc<()>)> = (0..6).map(|x| (x, Rc::clone(&shared))).collect();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        sec.retain_mut(|(x, _)| {
+            assert!(*x != 3, "predicate failed");
+            *x += 100;
+            *x % 2 == 0
+        })
+    }));
+    assert!(result.is_err());
+
+    // 1 was removed, 3 panicked before being modified, the rest is untouched
+    let remaining: Vec<i32> = sec.iter().map(|(x, _)| *x).collect();
+    assert_eq!(remaining, [100, 102, 3, 4, 5]);
+    assert_eq!(Rc::strong_count(&shared), 6);
+
+    drop(sec);
+    assert_eq!(Rc::strong_count(&shared), 1);
+}